@@ -0,0 +1,310 @@
+// ansi_color.rs
+use crate::Color;
+
+/// The 16 classic ANSI colors
+///
+/// Unlike a [`Color`] they are printed with their own escape codes (`30` to `37` and
+/// `90` to `97`), so the terminal shows them with the colors of its theme.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{AnsiColor, ColorPrintExt};
+///
+/// println!("{}", "Hello, world!".fg(AnsiColor::BrightGreen));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl AnsiColor {
+    /// Every ANSI color ordered by its index
+    pub const ALL: [AnsiColor; 16] = [
+        AnsiColor::Black,
+        AnsiColor::Red,
+        AnsiColor::Green,
+        AnsiColor::Yellow,
+        AnsiColor::Blue,
+        AnsiColor::Magenta,
+        AnsiColor::Cyan,
+        AnsiColor::White,
+        AnsiColor::BrightBlack,
+        AnsiColor::BrightRed,
+        AnsiColor::BrightGreen,
+        AnsiColor::BrightYellow,
+        AnsiColor::BrightBlue,
+        AnsiColor::BrightMagenta,
+        AnsiColor::BrightCyan,
+        AnsiColor::BrightWhite,
+    ];
+
+    /// Return the index of the color, 0 to 7 for the standard colors and 8 to 15 for the bright ones
+    pub fn index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Return the color with the given index (0 to 15), `None` for bigger indexes
+    pub fn from_index(index: u8) -> Option<AnsiColor> {
+        AnsiColor::ALL.get(index as usize).copied()
+    }
+
+    /// Return the SGR parameter setting the color as foreground (`30` to `37`, `90` to `97`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::AnsiColor;
+    ///
+    /// assert_eq!(AnsiColor::Red.fg_param(), 31);
+    /// assert_eq!(AnsiColor::BrightRed.fg_param(), 91);
+    /// ```
+    pub fn fg_param(&self) -> u8 {
+        match self.index() {
+            index @ 0..=7 => 30 + index,
+            index => 90 + index - 8,
+        }
+    }
+
+    /// Return the SGR parameter setting the color as background (`40` to `47`, `100` to `107`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::AnsiColor;
+    ///
+    /// assert_eq!(AnsiColor::Red.bg_param(), 41);
+    /// assert_eq!(AnsiColor::BrightRed.bg_param(), 101);
+    /// ```
+    pub fn bg_param(&self) -> u8 {
+        self.fg_param() + 10
+    }
+
+    /// Return the lowercase name of the color, e.g. `bright_red`
+    pub fn name(&self) -> &'static str {
+        const NAMES: [&str; 16] = [
+            "black",
+            "red",
+            "green",
+            "yellow",
+            "blue",
+            "magenta",
+            "cyan",
+            "white",
+            "bright_black",
+            "bright_red",
+            "bright_green",
+            "bright_yellow",
+            "bright_blue",
+            "bright_magenta",
+            "bright_cyan",
+            "bright_white",
+        ];
+        NAMES[self.index() as usize]
+    }
+}
+
+/// Define the `red()`, `on_red()`, `bright_red()`, ... shortcuts of every [`AnsiColor`]
+///
+/// `impl` defines consuming methods returning `$ret` through `self.fg` and `self.bg`, `map_style`
+/// through the same shortcuts of the [`Style`](crate::Style) given to `self.map_style`, `trait`
+/// defines the default methods of [`StyleExt`](crate::StyleExt).
+macro_rules! ansi_color_methods {
+    (impl $ret:ty) => {
+        ansi_color_methods!(@impl $ret;
+            black on_black Black, red on_red Red, green on_green Green, yellow on_yellow Yellow,
+            blue on_blue Blue, magenta on_magenta Magenta, cyan on_cyan Cyan, white on_white White,
+            bright_black on_bright_black BrightBlack, bright_red on_bright_red BrightRed,
+            bright_green on_bright_green BrightGreen, bright_yellow on_bright_yellow BrightYellow,
+            bright_blue on_bright_blue BrightBlue, bright_magenta on_bright_magenta BrightMagenta,
+            bright_cyan on_bright_cyan BrightCyan, bright_white on_bright_white BrightWhite);
+    };
+    (map_style $ret:ty) => {
+        ansi_color_methods!(@map_style $ret;
+            black on_black Black, red on_red Red, green on_green Green, yellow on_yellow Yellow,
+            blue on_blue Blue, magenta on_magenta Magenta, cyan on_cyan Cyan, white on_white White,
+            bright_black on_bright_black BrightBlack, bright_red on_bright_red BrightRed,
+            bright_green on_bright_green BrightGreen, bright_yellow on_bright_yellow BrightYellow,
+            bright_blue on_bright_blue BrightBlue, bright_magenta on_bright_magenta BrightMagenta,
+            bright_cyan on_bright_cyan BrightCyan, bright_white on_bright_white BrightWhite);
+    };
+    (trait) => {
+        ansi_color_methods!(@trait;
+            black on_black Black, red on_red Red, green on_green Green, yellow on_yellow Yellow,
+            blue on_blue Blue, magenta on_magenta Magenta, cyan on_cyan Cyan, white on_white White,
+            bright_black on_bright_black BrightBlack, bright_red on_bright_red BrightRed,
+            bright_green on_bright_green BrightGreen, bright_yellow on_bright_yellow BrightYellow,
+            bright_blue on_bright_blue BrightBlue, bright_magenta on_bright_magenta BrightMagenta,
+            bright_cyan on_bright_cyan BrightCyan, bright_white on_bright_white BrightWhite);
+    };
+    (@impl $ret:ty; $($fg:ident $bg:ident $color:ident),*) => {
+        $(
+            #[doc = concat!("Set the color to `AnsiColor::", stringify!($color), "`")]
+            pub fn $fg(self) -> $ret {
+                self.fg(crate::AnsiColor::$color)
+            }
+
+            #[doc = concat!("Set the background to `AnsiColor::", stringify!($color), "`")]
+            pub fn $bg(self) -> $ret {
+                self.bg(crate::AnsiColor::$color)
+            }
+        )*
+    };
+    (@map_style $ret:ty; $($fg:ident $bg:ident $color:ident),*) => {
+        $(
+            #[doc = concat!("Set the color to `AnsiColor::", stringify!($color), "`")]
+            pub fn $fg(self) -> $ret {
+                self.map_style(crate::Style::$fg)
+            }
+
+            #[doc = concat!("Set the background to `AnsiColor::", stringify!($color), "`")]
+            pub fn $bg(self) -> $ret {
+                self.map_style(crate::Style::$bg)
+            }
+        )*
+    };
+    (@trait; $($fg:ident $bg:ident $color:ident),*) => {
+        $(
+            #[doc = concat!("Style the value with the color `AnsiColor::", stringify!($color), "`")]
+            fn $fg(&self) -> Styled<&Self> {
+                self.styled().fg(crate::AnsiColor::$color)
+            }
+
+            #[doc = concat!("Style the value with the background `AnsiColor::", stringify!($color), "`")]
+            fn $bg(&self) -> Styled<&Self> {
+                self.styled().bg(crate::AnsiColor::$color)
+            }
+        )*
+    };
+}
+
+impl From<AnsiColor> for Color {
+    /// The xterm default value of the ANSI color
+    fn from(color: AnsiColor) -> Color {
+        Color::from_ansi256(color.index())
+    }
+}
+
+/// A color as printed by the terminal: a RGB color or one of the theme's ANSI colors
+///
+/// Everything convertible into a [`Color`] or an [`AnsiColor`] converts into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TermColor {
+    /// A RGB color, printed according to the color mode
+    Rgb(Color),
+    /// An ANSI color, always printed with its own escape code
+    Ansi(AnsiColor),
+}
+
+impl TermColor {
+    /// Return the RGB value of the color, the xterm default value for ANSI colors
+    pub fn to_rgb(&self) -> Color {
+        match self {
+            TermColor::Rgb(color) => *color,
+            TermColor::Ansi(color) => (*color).into(),
+        }
+    }
+}
+
+/// Serialized as the color name for ANSI colors (`bright_red`) and as a hex string for RGB colors
+#[cfg(feature = "serde")]
+impl serde::Serialize for TermColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TermColor::Rgb(color) => color.serialize(serializer),
+            TermColor::Ansi(color) => serializer.serialize_str(color.name()),
+        }
+    }
+}
+
+/// Deserialized from an ANSI color name, or anything a [`Color`] is deserialized from
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TermColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<TermColor, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Rgb(Color),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Name(name) => match AnsiColor::ALL.iter().find(|color| color.name() == name) {
+                Some(color) => Ok(TermColor::Ansi(*color)),
+                None => name.parse().map(TermColor::Rgb).map_err(serde::de::Error::custom),
+            },
+            Repr::Rgb(color) => Ok(TermColor::Rgb(color)),
+        }
+    }
+}
+
+impl From<Color> for TermColor {
+    fn from(color: Color) -> TermColor {
+        TermColor::Rgb(color)
+    }
+}
+
+impl From<(u8, u8, u8)> for TermColor {
+    fn from(color: (u8, u8, u8)) -> TermColor {
+        TermColor::Rgb(color.into())
+    }
+}
+
+impl From<AnsiColor> for TermColor {
+    fn from(color: AnsiColor) -> TermColor {
+        TermColor::Ansi(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_color() {
+        assert_eq!(AnsiColor::Black.fg_param(), 30);
+        assert_eq!(AnsiColor::White.fg_param(), 37);
+        assert_eq!(AnsiColor::BrightBlack.fg_param(), 90);
+        assert_eq!(AnsiColor::BrightWhite.fg_param(), 97);
+        assert_eq!(AnsiColor::Black.bg_param(), 40);
+        assert_eq!(AnsiColor::BrightWhite.bg_param(), 107);
+        assert_eq!(AnsiColor::from_index(12), Some(AnsiColor::BrightBlue));
+        assert_eq!(AnsiColor::from_index(16), None);
+        assert_eq!(AnsiColor::BrightMagenta.name(), "bright_magenta");
+        assert_eq!(Color::from(AnsiColor::BrightRed), Color::RED);
+    }
+
+    #[test]
+    fn test_term_color() {
+        assert_eq!(TermColor::from((1, 2, 3)), TermColor::Rgb(Color::rgb(1, 2, 3)));
+        assert_eq!(TermColor::from(AnsiColor::Red).to_rgb(), Color::rgb(205, 0, 0));
+        assert_eq!(TermColor::from(Color::BLUE).to_rgb(), Color::BLUE);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_term_color_serde() {
+        let ansi = TermColor::Ansi(AnsiColor::BrightRed);
+        assert_eq!(serde_json::to_string(&ansi).unwrap(), "\"bright_red\"");
+        assert_eq!(serde_json::from_str::<TermColor>("\"bright_red\"").unwrap(), ansi);
+        assert_eq!(serde_json::to_string(&TermColor::Rgb(Color::RED)).unwrap(), "\"#ff0000\"");
+        assert_eq!(serde_json::from_str::<TermColor>("\"#ff0000\"").unwrap(), TermColor::Rgb(Color::RED));
+        assert_eq!(serde_json::from_str::<TermColor>("[0, 0, 255]").unwrap(), TermColor::Rgb(Color::BLUE));
+        assert!(serde_json::from_str::<TermColor>("\"not a color\"").is_err());
+    }
+}
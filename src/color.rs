@@ -1,49 +1,1426 @@
 // lib.rs
+use crate::{Color, Style, TermColor, TextStyle};
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicU8, Ordering};
+#[cfg(feature = "ansi")]
+use crate::gradient::{CharPositions, TextLayout};
+#[cfg(feature = "ansi")]
+use crate::utils::graphemes;
+#[cfg(feature = "ansi")]
+use crate::{ColorUnit, Gradient, Interpolation, Repeat};
+#[cfg(feature = "ansi")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
 /// This enum represents the underline style of the text
 /// 
 /// # Variants
-/// - `String`: The underline style is a string (for now only 'stripe' is supported)
+/// - `String`: The underline style is a string: `stripe`, or one of the shapes `double`, `curly`,
+///   `dotted` and `dashed`
 /// - `None`: The underline style is none (normal underline)
-#[derive(Clone)]
+///
+/// The shapes are printed as a normal underline on terminals without support for them,
+/// see [`utils::supports_underline_styles`](crate::utils::supports_underline_styles).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UnderlineStyle {
-    /// The underline style is a string (for now only 'stripe' is supported)
+    /// The underline style is a string: `stripe`, `double`, `curly`, `dotted` or `dashed`
     String(String),
     /// The underline style is none (normal underline)
     None,
 }
-/// A struct for color printing contains the text, color and underline style
-pub struct ColorPrint<'a>(&'a str, Option<(u8, u8, u8)>, UnderlineStyle);
+#[cfg(feature = "ansi")]
+impl UnderlineStyle {
+    /// Return the escape code of the underline style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::UnderlineStyle;
+    ///
+    /// assert_eq!(UnderlineStyle::String("stripe".to_string()).sgr_code(), "\x1b[9m");
+    /// ```
+    pub fn sgr_code(&self) -> &'static str {
+        match self.sgr_param() {
+            Some("4") => "\x1b[4m",
+            Some("9") => "\x1b[9m",
+            Some("4:2") => "\x1b[4:2m",
+            Some("4:3") => "\x1b[4:3m",
+            Some("4:4") => "\x1b[4:4m",
+            Some("4:5") => "\x1b[4:5m",
+            _ => "",
+        }
+    }
+
+    /// Return the SGR parameter of the underline style, `None` if it prints nothing
+    pub(crate) fn sgr_param(&self) -> Option<&'static str> {
+        match self {
+            UnderlineStyle::String(style) => match style.as_str() {
+                "normal" => Some("4"),
+                "stripe" => Some("9"),
+                "double" => Some("4:2"),
+                "curly" => Some("4:3"),
+                "dotted" => Some("4:4"),
+                "dashed" => Some("4:5"),
+                _ => None,
+            },
+            UnderlineStyle::None => Some("4"),
+        }
+    }
+}
+
+impl UnderlineStyle {
+    /// Shapes of the underline printed with `4:n` codes, not understood by every terminal
+    const SHAPES: [&'static str; 4] = ["double", "curly", "dotted", "dashed"];
+
+    /// Return true for an underline shape (`double`, `curly`, `dotted` or `dashed`)
+    pub fn is_shape(&self) -> bool {
+        matches!(self, UnderlineStyle::String(style) if UnderlineStyle::SHAPES.contains(&style.as_str()))
+    }
+
+    /// Return the style printed on terminals without underline shapes, a normal underline
+    /// replaces the shape and any other style is kept
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::UnderlineStyle;
+    ///
+    /// assert_eq!(UnderlineStyle::String("curly".to_string()).degrade(), UnderlineStyle::None);
+    /// assert_eq!(UnderlineStyle::None.degrade(), UnderlineStyle::None);
+    /// ```
+    pub fn degrade(&self) -> UnderlineStyle {
+        if self.is_shape() {
+            UnderlineStyle::None
+        } else {
+            self.clone()
+        }
+    }
+}
+
+/// Serialized as the style name, `normal` for [`UnderlineStyle::None`]
+#[cfg(feature = "serde")]
+impl serde::Serialize for UnderlineStyle {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            UnderlineStyle::String(style) => serializer.serialize_str(style),
+            UnderlineStyle::None => serializer.serialize_str("normal"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UnderlineStyle {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<UnderlineStyle, D::Error> {
+        let style = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(if style == "normal" { UnderlineStyle::None } else { UnderlineStyle::String(style) })
+    }
+}
+
+/// The kind of escape codes used to print colors
+///
+/// # Variants
+/// - `TrueColor`: 24-bit colors (`\x1b[38;2;r;g;bm`), the default
+/// - `Ansi256`: The nearest color of the xterm 256 color palette (`\x1b[38;5;nm`)
+/// - `Ansi16`: The nearest of the 16 classic ANSI colors (`\x1b[31m`, `\x1b[91m`, ...)
+/// - `None`: No escape codes at all, only the text is printed
+///
+/// The mode is chosen per text with [`ColorPrint::mode`], otherwise the global
+/// mode is used: the one set with [`ColorMode::set_global`] or the one detected
+/// from the terminal with [`crate::utils::detect_color_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum ColorMode {
+    /// 24-bit colors
+    #[default]
+    TrueColor,
+    /// The nearest color of the xterm 256 color palette
+    Ansi256,
+    /// The nearest of the 16 classic ANSI colors
+    Ansi16,
+    /// No escape codes at all
+    None,
+}
+
+/// The global color mode, stored as its index in `ColorMode` or `u8::MAX` to detect it
+static GLOBAL_COLOR_MODE: AtomicU8 = AtomicU8::new(u8::MAX);
+
+impl ColorMode {
+    /// Set the color mode used by every text without its own mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorMode, ColorPrintExt};
+    ///
+    /// ColorMode::set_global(ColorMode::Ansi16);
+    /// assert_eq!(ColorMode::global(), ColorMode::Ansi16);
+    /// println!("{}", "Hello, world!".color(255, 0, 0)); // Printed as \x1b[91m
+    /// ```
+    pub fn set_global(mode: ColorMode) {
+        GLOBAL_COLOR_MODE.store(mode as u8, Ordering::Relaxed);
+    }
+
+    /// Set the global color mode until the guard is dropped, the tests holding a guard run one at a time
+    #[cfg(all(test, feature = "ansi"))]
+    pub(crate) fn global_guard(mode: ColorMode) -> GlobalModeGuard {
+        static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+        let lock = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let previous = GLOBAL_COLOR_MODE.swap(mode as u8, Ordering::Relaxed);
+        GlobalModeGuard { previous, _lock: lock }
+    }
+
+    /// Get the color mode used by every text without its own mode
+    ///
+    /// Unless it was set with [`ColorMode::set_global`] it is the mode detected from the terminal.
+    pub fn global() -> ColorMode {
+        match GLOBAL_COLOR_MODE.load(Ordering::Relaxed) {
+            0 => ColorMode::TrueColor,
+            1 => ColorMode::Ansi256,
+            2 => ColorMode::Ansi16,
+            3 => ColorMode::None,
+            _ => crate::utils::detect_color_mode(),
+        }
+    }
+
+    /// Return the escape code setting `color` as the foreground color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorMode};
+    ///
+    /// assert_eq!(ColorMode::Ansi256.fg_code(Color::RED), "\x1b[38;5;196m");
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn fg_code(&self, color: Color) -> String {
+        match self.fg_param(color) {
+            Some(param) => format!("\x1b[{}m", param),
+            None => "".to_string(),
+        }
+    }
+
+    /// Return the escape code setting `color` as the background color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorMode};
+    ///
+    /// assert_eq!(ColorMode::TrueColor.bg_code(Color::RED), "\x1b[48;2;255;0;0m");
+    /// assert_eq!(ColorMode::Ansi16.bg_code(Color::RED), "\x1b[101m");
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn bg_code(&self, color: Color) -> String {
+        match self.bg_param(color) {
+            Some(param) => format!("\x1b[{}m", param),
+            None => "".to_string(),
+        }
+    }
+
+    /// Return the escape code (SGR 58) setting `color` as the underline color
+    ///
+    /// There is no 16 color underline code, [`ColorMode::Ansi16`] uses the 256 color code
+    /// with the index of the nearest ANSI color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorMode};
+    ///
+    /// assert_eq!(ColorMode::TrueColor.underline_color_code(Color::RED), "\x1b[58;2;255;0;0m");
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn underline_color_code(&self, color: Color) -> String {
+        match self.underline_color_param(color) {
+            Some(param) => format!("\x1b[{}m", param),
+            None => "".to_string(),
+        }
+    }
+
+    /// Return the SGR parameters setting `color` as the foreground color, without the escape
+    #[cfg(feature = "ansi")]
+    pub(crate) fn fg_param(&self, color: Color) -> Option<SgrColor> {
+        match self {
+            ColorMode::TrueColor => Some(SgrColor::Rgb(38, color)),
+            ColorMode::Ansi256 => Some(SgrColor::Indexed(38, color.to_ansi256())),
+            ColorMode::Ansi16 => match color.to_ansi16() {
+                index @ 0..=7 => Some(SgrColor::Basic(30 + index)),
+                index => Some(SgrColor::Basic(90 + index - 8)),
+            },
+            ColorMode::None => None,
+        }
+    }
+
+    /// Return the SGR parameters setting `color` as the background color, without the escape
+    #[cfg(feature = "ansi")]
+    pub(crate) fn bg_param(&self, color: Color) -> Option<SgrColor> {
+        match self {
+            ColorMode::TrueColor => Some(SgrColor::Rgb(48, color)),
+            ColorMode::Ansi256 => Some(SgrColor::Indexed(48, color.to_ansi256())),
+            ColorMode::Ansi16 => match color.to_ansi16() {
+                index @ 0..=7 => Some(SgrColor::Basic(40 + index)),
+                index => Some(SgrColor::Basic(100 + index - 8)),
+            },
+            ColorMode::None => None,
+        }
+    }
+
+    /// Return the SGR parameters setting `color` as the underline color, without the escape
+    #[cfg(feature = "ansi")]
+    pub(crate) fn underline_color_param(&self, color: Color) -> Option<SgrColor> {
+        match self {
+            ColorMode::TrueColor => Some(SgrColor::Rgb(58, color)),
+            ColorMode::Ansi256 => Some(SgrColor::Indexed(58, color.to_ansi256())),
+            ColorMode::Ansi16 => Some(SgrColor::Indexed(58, color.to_ansi16())),
+            ColorMode::None => None,
+        }
+    }
+}
+
+/// Restores the global color mode when dropped, see [`ColorMode::global_guard`]
+#[cfg(all(test, feature = "ansi"))]
+pub(crate) struct GlobalModeGuard {
+    previous: u8,
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+#[cfg(all(test, feature = "ansi"))]
+impl Drop for GlobalModeGuard {
+    fn drop(&mut self) {
+        GLOBAL_COLOR_MODE.store(self.previous, Ordering::Relaxed);
+    }
+}
+
+/// The SGR parameters of a color, printed without allocating
+#[cfg(feature = "ansi")]
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum SgrColor {
+    /// `base;2;r;g;b`
+    Rgb(u8, Color),
+    /// `base;5;index`
+    Indexed(u8, u8),
+    /// A single parameter like `31`
+    Basic(u8),
+}
+
+#[cfg(feature = "ansi")]
+impl std::fmt::Display for SgrColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SgrColor::Rgb(base, color) => write!(f, "{};2;{};{};{}", base, color.r, color.g, color.b),
+            SgrColor::Indexed(base, index) => write!(f, "{};5;{}", base, index),
+            SgrColor::Basic(param) => write!(f, "{}", param),
+        }
+    }
+}
+
+/// Whether escape codes are printed at all, e.g. from a `--color` command line flag
+///
+/// # Variants
+/// - `Auto`: Print them according to the color mode of each text or the global one, the default
+/// - `Always`: Print them even when the terminal was detected without colors
+/// - `Never`: Never print them, not even for texts with their own color mode or gradients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Follow the color modes
+    #[default]
+    Auto,
+    /// Print colors even when the terminal was detected without them
+    Always,
+    /// Print only the text
+    Never,
+}
+
+/// The global color choice, stored as its index in `ColorChoice`
+static GLOBAL_COLOR_CHOICE: AtomicU8 = AtomicU8::new(0);
+
+impl ColorChoice {
+    /// Set the color choice of every text, gradient and styled line
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorChoice, ColorPrintExt};
+    ///
+    /// let no_color = std::env::args().any(|arg| arg == "--color=never");
+    /// if no_color {
+    ///     ColorChoice::set_global(ColorChoice::Never);
+    /// }
+    /// println!("{}", "Hello, world!".color(255, 0, 0));
+    /// ```
+    pub fn set_global(choice: ColorChoice) {
+        GLOBAL_COLOR_CHOICE.store(choice as u8, Ordering::Relaxed);
+    }
+
+    /// Get the color choice set with [`ColorChoice::set_global`], [`ColorChoice::Auto`] by default
+    pub fn global() -> ColorChoice {
+        match GLOBAL_COLOR_CHOICE.load(Ordering::Relaxed) {
+            1 => ColorChoice::Always,
+            2 => ColorChoice::Never,
+            _ => ColorChoice::Auto,
+        }
+    }
+
+    /// Return the color mode used to print a text with the given mode, or without one
+    ///
+    /// # Params
+    /// - `mode`: The color mode of the text, the global one ([`ColorMode::global`]) if `None`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorChoice, ColorMode};
+    ///
+    /// assert_eq!(ColorChoice::Auto.resolve(Some(ColorMode::Ansi256)), ColorMode::Ansi256);
+    /// assert_eq!(ColorChoice::Never.resolve(Some(ColorMode::Ansi256)), ColorMode::None);
+    /// ```
+    pub fn resolve(&self, mode: Option<ColorMode>) -> ColorMode {
+        match self {
+            ColorChoice::Auto => mode.unwrap_or_else(ColorMode::global),
+            ColorChoice::Always => mode.unwrap_or_else(|| match ColorMode::global() {
+                ColorMode::None => ColorMode::default(),
+                global => global,
+            }),
+            ColorChoice::Never => ColorMode::None,
+        }
+    }
+}
+
+/// The escape code printing a `double` underline
+///
+/// Terminals with underline shapes understand `4:2`. The older `21` is a double underline for
+/// xterm but means "bold off" for several other terminals, so it is only used when asked or
+/// when running in xterm.
+///
+/// # Variants
+/// - `Extended`: `\x1b[4:2m`, the default where the underline shapes are supported
+/// - `Legacy`: `\x1b[21m`, the default in xterm
+/// - `Single`: A normal underline `\x1b[4m`, the default everywhere else
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DoubleUnderline {
+    /// `\x1b[4:2m`
+    Extended,
+    /// `\x1b[21m`
+    Legacy,
+    /// A normal underline
+    Single,
+}
+
+/// The global double underline code, stored as its index in `DoubleUnderline`, `u8::MAX` to detect it
+static GLOBAL_DOUBLE_UNDERLINE: AtomicU8 = AtomicU8::new(u8::MAX);
+
+impl DoubleUnderline {
+    /// Set the escape code of every double underline, instead of choosing it from the terminal
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorMode, ColorPrintExt, DoubleUnderline};
+    ///
+    /// // Printed with `\x1b[21m`
+    /// DoubleUnderline::set_global(DoubleUnderline::Legacy);
+    /// let text = "Total";
+    /// println!("{}", text.underline(Some("double")).mode(ColorMode::TrueColor));
+    /// ```
+    pub fn set_global(code: DoubleUnderline) {
+        GLOBAL_DOUBLE_UNDERLINE.store(code as u8, Ordering::Relaxed);
+    }
+
+    /// Get the escape code set with [`DoubleUnderline::set_global`], or the one for the terminal
+    pub fn global() -> DoubleUnderline {
+        match GLOBAL_DOUBLE_UNDERLINE.load(Ordering::Relaxed) {
+            0 => DoubleUnderline::Extended,
+            1 => DoubleUnderline::Legacy,
+            2 => DoubleUnderline::Single,
+            _ if crate::utils::supports_underline_styles() => DoubleUnderline::Extended,
+            _ if crate::utils::is_xterm() => DoubleUnderline::Legacy,
+            _ => DoubleUnderline::Single,
+        }
+    }
+
+    /// Return the SGR parameter of the double underline
+    #[cfg(feature = "ansi")]
+    pub(crate) fn sgr_param(&self) -> &'static str {
+        match self {
+            DoubleUnderline::Extended => "4:2",
+            DoubleUnderline::Legacy => "21",
+            DoubleUnderline::Single => "4",
+        }
+    }
+}
+
+/// A struct for color printing contains the text and its [`Style`]: color, underline style and color mode
+///
+/// The text is borrowed or owned, so styled text built from a `String` can be returned from a function.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Color, ColorPrint};
+///
+/// fn status(done: usize, total: usize) -> ColorPrint<'static> {
+///     ColorPrint::from(format!("{}/{} done", done, total)).map_style(|style| style.fg(Color::GREEN))
+/// }
+///
+/// println!("{}", status(3, 5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColorPrint<'a>(Cow<'a, str>, Style);
+
+/// Serialized as a `text` and a `style` field
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for ColorPrint<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ColorPrint", 2)?;
+        state.serialize_field("text", &self.0)?;
+        state.serialize_field("style", &self.1)?;
+        state.end()
+    }
+}
+
+/// Deserialized from a `text` and an optional `style` field, the text is owned
+#[cfg(feature = "serde")]
+impl<'de, 'a> serde::Deserialize<'de> for ColorPrint<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<ColorPrint<'a>, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Repr {
+            text: String,
+            #[serde(default)]
+            style: Style,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        Ok(ColorPrint(Cow::Owned(repr.text), repr.style))
+    }
+}
+
+impl<'a> From<&'a str> for ColorPrint<'a> {
+    fn from(text: &'a str) -> ColorPrint<'a> {
+        ColorPrint(Cow::Borrowed(text), Style::new())
+    }
+}
+
+impl From<String> for ColorPrint<'static> {
+    fn from(text: String) -> ColorPrint<'static> {
+        ColorPrint(Cow::Owned(text), Style::new())
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for ColorPrint<'a> {
+    fn from(text: Cow<'a, str>) -> ColorPrint<'a> {
+        ColorPrint(text, Style::new())
+    }
+}
+
+impl<'a> std::fmt::Display for ColorPrint<'a> {
+    /// Print the text with color and underline style
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    /// 
+    /// println!("{}", "Hello, world!".color(255, 0, 0).underline(None)); // Color and underline
+    /// println!("{}", "Hello, world!".color(0, 0, 255).underline(Some("stripe"))); // Color and striped 
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.1.write_styled(f, &self.0)
+    }
+}
+
+impl<'a> ColorPrint<'a> {
+    /// Get the underline style of the text, `None` if it is not underlined
+    pub fn underline_style(&self) -> Option<&UnderlineStyle> {
+        self.1.underline.as_ref()
+    }
+
+    /// Get the style of the text
+    pub fn style(&self) -> &Style {
+        &self.1
+    }
+
+    /// Replace the style of the text
+    ///
+    /// # Params
+    /// - `style`: The new style
+    pub fn with_style(self, style: Style) -> ColorPrint<'a> {
+        ColorPrint(self.0, style)
+    }
+
+    /// Change the style of the text, keeping an owned text owned
+    ///
+    /// # Params
+    /// - `f`: Returns the new style from the current one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrint};
+    ///
+    /// let warning = ColorPrint::from(String::from("disk almost full")).map_style(|style| style.fg(Color::ORANGE));
+    /// println!("{}", warning.bold());
+    /// ```
+    pub fn map_style(self, f: impl FnOnce(Style) -> Style) -> ColorPrint<'a> {
+        ColorPrint(self.0, f(self.1))
+    }
+
+    /// Set the kind of escape codes used to print the color
+    ///
+    /// # Params
+    /// - `mode`: The color mode, the global one ([`ColorMode::global`]) by default
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorMode, ColorPrintExt};
+    ///
+    /// // For terminals without 24-bit color support
+    /// println!("{}", "Hello, world!".color(255, 136, 0).mode(ColorMode::Ansi256));
+    /// ```
+    pub fn mode(self, mode: ColorMode) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.mode(mode))
+    }
+
+    /// Set the background color of the text, see [`Style::bg`]
+    pub fn bg(self, color: impl Into<TermColor>) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.bg(color))
+    }
+
+    ansi_color_methods!(map_style ColorPrint<'a>);
+
+    /// Color the text with a [`Gradient`](crate::Gradient) instead of a single color, see [`Style::gradient_fill`]
+    pub fn gradient_fill(self, gradient: crate::Gradient) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.gradient_fill(gradient))
+    }
+
+    /// Color the background with a [`Gradient`](crate::Gradient), see [`Style::gradient_bg_fill`]
+    pub fn gradient_bg_fill(self, gradient: crate::Gradient) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.gradient_bg_fill(gradient))
+    }
+
+    /// Color the text with a gradient preset, see [`Style::gradient_preset`]
+    pub fn gradient_preset(self, preset: crate::gradients::Preset) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.gradient_preset(preset))
+    }
+
+    /// Enable a text style, see [`Style::text_style`]
+    pub fn text_style(self, style: TextStyle) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.text_style(style))
+    }
+
+    /// Print the text in bold
+    pub fn bold(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.bold())
+    }
+
+    /// Print the text with decreased intensity
+    pub fn dim(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.dim())
+    }
+
+    /// Print the text in italic
+    pub fn italic(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.italic())
+    }
+
+    /// Draw a line through the text, it can be combined with an underline
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    ///
+    /// println!("{}", "deprecated_fn".underline(None).strikethrough());
+    /// ```
+    pub fn strikethrough(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.strikethrough())
+    }
+
+    /// Replace the underline with a line through the text, see [`Style::stripe`]
+    #[deprecated(since = "2.0.3", note = "use `strikethrough`, which can be combined with an underline")]
+    #[allow(deprecated)]
+    pub fn stripe(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.stripe())
+    }
+
+    /// Draw a line above the text (SGR 53), not supported by every terminal
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// println!("{}", ColorPrint::from("Total").overline());
+    /// ```
+    pub fn overline(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.overline())
+    }
+
+    /// Fill the rest of the line with the background color, see [`Style::fill_line`]
+    pub fn fill_line(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.fill_line())
+    }
+
+    /// Set the color of the underline, shown by terminals supporting colored underlines
+    ///
+    /// # Params
+    /// - `r`: Red color value
+    /// - `g`: Green color value
+    /// - `b`: Blue color value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    ///
+    /// // Red underline like an editor error
+    /// println!("{}", "let x = ;".underline(None).underline_color(255, 0, 0));
+    /// ```
+    pub fn underline_color(self, r: u8, g: u8, b: u8) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.underline_color(r, g, b))
+    }
+
+    /// Keep the style only if `condition` is true, otherwise print the plain text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    ///
+    /// let failed = true;
+    /// println!("{}", "3 tests failed".color(255, 0, 0).when(failed));
+    /// ```
+    pub fn when(self, condition: bool) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.when(condition))
+    }
+
+    /// Keep the style only if `condition` is false, the opposite of [`ColorPrint::when`]
+    pub fn unless(self, condition: bool) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.unless(condition))
+    }
+
+    /// Convert into a `ColorPrint` owning its text
+    pub fn into_owned(self) -> ColorPrint<'static> {
+        ColorPrint(Cow::Owned(self.0.into_owned()), self.1)
+    }
+
+    /// Return the text without style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    ///
+    /// let error = "file not found";
+    /// let error = error.color(255, 0, 0).bold();
+    /// assert_eq!(error.text(), "file not found");
+    /// ```
+    pub fn text(&self) -> &str {
+        &self.0
+    }
+
+    /// Return the text without style and without the escape codes it contains, e.g. from a
+    /// nested styled value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, ColorPrintExt};
+    ///
+    /// let name = "cargo";
+    /// let message = ColorPrint::from(format!("{} failed", name.color(0, 255, 0)));
+    /// assert_eq!(message.plain(), "cargo failed");
+    /// ```
+    pub fn plain(&self) -> String {
+        crate::utils::strip_ansi(&self.0)
+    }
+
+    /// Return the length of the text in bytes, like [`str::len`]
+    ///
+    /// Use [`ColorPrint::display_width`] for the columns it takes in the terminal.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return true if the text is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Create a `ColorPrint` with the same style for another text
+    pub(crate) fn restyle<'b>(&self, text: impl Into<Cow<'b, str>>) -> ColorPrint<'b> {
+        ColorPrint(text.into(), self.1.clone())
+    }
+
+    /// Split the text in two at the character `index`, both parts keeping the style
+    pub(crate) fn split_at_char(self, index: usize) -> (ColorPrint<'a>, ColorPrint<'a>) {
+        let byte = self.0.char_indices().nth(index).map_or(self.0.len(), |(byte, _)| byte);
+        let (head, tail) = match self.0 {
+            Cow::Borrowed(text) => (Cow::Borrowed(&text[..byte]), Cow::Borrowed(&text[byte..])),
+            Cow::Owned(mut text) => {
+                let tail = text.split_off(byte);
+                (Cow::Owned(text), Cow::Owned(tail))
+            }
+        };
+        (ColorPrint(head, self.1.clone()), ColorPrint(tail, self.1))
+    }
+
+    /// Apply `style` over the characters in `range`, the rest of the text keeps its style
+    ///
+    /// The fields set in `style` override the ones of the text, see [`Style::merge`]. The
+    /// range counts characters, not bytes, and is clamped to the length of the text.
+    ///
+    /// # Params
+    /// - `range`: The characters to style
+    /// - `style`: The style applied over them
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, Style};
+    ///
+    /// let message = ColorPrint::from("expected `;` at column 12");
+    /// println!("{}", message.style_range(9..12, &Style::new().color(255, 0, 0).bold()));
+    /// ```
+    pub fn style_range(self, range: std::ops::Range<usize>, style: &Style) -> crate::StyledLine<'a> {
+        crate::StyledLine::from(self).style_range(range, style)
+    }
+
+    /// Apply `style` over every match of `pattern`, see [`StyledLine::highlight_matches`](crate::StyledLine::highlight_matches)
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn highlight_matches(self, pattern: &regex::Regex, style: &Style) -> crate::StyledLine<'a> {
+        crate::StyledLine::from(self).highlight_matches(pattern, style)
+    }
+
+    /// Apply `style` over every occurrence of `needle`, see [`StyledLine::highlight`](crate::StyledLine::highlight)
+    pub fn highlight(self, needle: &str, style: &Style) -> crate::StyledLine<'a> {
+        crate::StyledLine::from(self).highlight(needle, style)
+    }
+
+    /// Return the number of columns the text takes in the terminal, without the escape codes
+    ///
+    /// Wide characters (CJK, emoji) take two columns, see [`display_width`](crate::utils::display_width).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    ///
+    /// let name = "東京".color(255, 136, 0);
+    /// println!("[{}{}]", name, " ".repeat(10 - name.display_width()));
+    /// ```
+    pub fn display_width(&self) -> usize {
+        crate::utils::display_width(&self.0)
+    }
+
+    /// Write the styled text to `writer`, without building a `String` first
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    /// use std::io::Write;
+    ///
+    /// let mut stdout = std::io::stdout().lock();
+    /// "Hello, world!".color(255, 0, 0).write_to(&mut stdout).unwrap();
+    /// writeln!(stdout).unwrap();
+    /// ```
+    pub fn write_to(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Append the styled text to `buffer`, reusing its allocation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorMode, ColorPrintExt};
+    ///
+    /// let mut frame = String::with_capacity(1024);
+    /// for tick in 0..3 {
+    ///     frame.clear();
+    ///     "tick".color(0, 255, 0).mode(ColorMode::None).render_into(&mut frame);
+    ///     assert_eq!(frame, "tick");
+    /// }
+    /// ```
+    pub fn render_into(&self, buffer: &mut String) {
+        use std::fmt::Write;
+        // Writing into a String never fails
+        let _ = write!(buffer, "{}", self);
+    }
+
+    /// Invert the color of the text when it is hard to read on the terminal background
+    ///
+    /// A dark color, chosen for a light background, is inverted on a dark background
+    /// ([`is_dark_background`](crate::utils::is_dark_background)) and a light color on a light
+    /// one. Theme colors and texts without color are left as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrint};
+    ///
+    /// let text = ColorPrint::from("Designed for a white page").map_style(|style| style.fg(Color::rgb(20, 20, 60)));
+    /// println!("{}", text.invert_if_dark());
+    /// ```
+    pub fn invert_if_dark(self) -> ColorPrint<'a> {
+        match self.1.fg {
+            Some(TermColor::Rgb(color)) => {
+                // A dark color is the most readable with white text on top of it
+                let dark = color.readable_fg() == Color::WHITE;
+                if dark == crate::utils::is_dark_background() {
+                    self.map_style(|style| style.fg(color.invert()))
+                } else {
+                    self
+                }
+            }
+            _ => self,
+        }
+    }
+
+    /// Keep the escape codes away from the tabs of the text, see [`Style::tab_safe`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Style;
+    ///
+    /// let row = Style::new().gradient((255, 0, 0), (0, 0, 255)).apply("name\tvalue").tab_safe().to_string();
+    /// assert!(row.contains('\t') && !row.contains("m\t") && !row.contains("\t\x1b"));
+    /// ```
+    pub fn tab_safe(self) -> ColorPrint<'a> {
+        ColorPrint(self.0, self.1.tab_safe())
+    }
+
+    /// Render the text with readable tokens in place of the escape codes
+    ///
+    /// Useful to see what is emitted, e.g. `[fg:255,0,0][underline]text[reset]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrintExt;
+    ///
+    /// assert_eq!("Hello".underline(None).strikethrough().render_debug(), "[strikethrough][underline]Hello[reset]");
+    /// ```
+    pub fn render_debug(&self) -> String {
+        format!("{}{}[reset]", self.1.debug_tokens(), self.0)
+    }
+}
+
+#[cfg(feature = "ansi")]
+impl<'a> ColorPrint<'a> {
+    /// Paint the text with a gradient from `start` to `end`
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `start`: The color of the first character
+    /// - `end`: The color of the last character
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// println!("{}", ColorPrint::gradient("Hello, world!", (255, 0, 0), (0, 0, 255)));
+    /// ```
+    pub fn gradient(text: &str, start: impl Into<Color>, end: impl Into<Color>) -> String {
+        Self::gradient_in(text, start, end, Interpolation::Rgb)
+    }
+
+    /// Paint the text with a gradient from `start` to `end` interpolated in the given color space
+    ///
+    /// [`Interpolation::Oklab`] avoids the muddy midpoints of RGB, e.g. red to green through brown.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `start`: The color of the first character
+    /// - `end`: The color of the last character
+    /// - `interpolation`: The color space used to interpolate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, Interpolation};
+    ///
+    /// println!("{}", ColorPrint::gradient_in("Hello, world!", (255, 0, 0), (0, 255, 0), Interpolation::Oklab));
+    /// ```
+    pub fn gradient_in(
+        text: &str,
+        start: impl Into<Color>,
+        end: impl Into<Color>,
+        interpolation: Interpolation,
+    ) -> String {
+        let gradient = Gradient::new(&[start.into(), end.into()]).interpolation(interpolation);
+        Self::gradient_with(text, &gradient)
+    }
+
+    /// Paint the background of the text with a gradient from `start` to `end`
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `start`: The background of the first character
+    /// - `end`: The background of the last character
+    /// - `fg`: The color of the text, `None` to keep the one of the terminal
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrint};
+    ///
+    /// println!("{}", ColorPrint::gradient_bg(" Build passed ", (0, 90, 40), (0, 140, 120), Some(Color::WHITE)));
+    /// ```
+    pub fn gradient_bg(text: &str, start: impl Into<Color>, end: impl Into<Color>, fg: Option<Color>) -> String {
+        if text.is_empty() {
+            return String::new();
+        }
+        let style = Style::new().gradient_bg(start, end);
+        match fg {
+            Some(color) => style.fg(color).apply(text).to_string(),
+            None => style.apply(text).to_string(),
+        }
+    }
+
+    /// Paint the text with a gradient through several evenly spaced colors
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `colors`: The colors from the first character to the last one, the text is left plain if it is empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// let flag = [(91, 206, 250), (245, 169, 184), (255, 255, 255), (245, 169, 184), (91, 206, 250)];
+    /// println!("{}", ColorPrint::gradient_multi("Hello, world!", &flag));
+    /// ```
+    pub fn gradient_multi<C: Into<Color> + Copy>(text: &str, colors: &[C]) -> String {
+        Self::gradient_with(text, &Gradient::new(colors))
+    }
+
+    /// Paint the text with a [`Gradient`], e.g. with stops at custom positions
+    ///
+    /// The colors are written in the global color mode, see [`Gradient::paint`] to choose another one.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `gradient`: The gradient from the first character to the last one, the text is left
+    ///   plain if it is empty
+    pub fn gradient_with(text: &str, gradient: &Gradient) -> String {
+        use std::fmt::Write;
+
+        let mut painted = String::with_capacity(painted_capacity(text));
+        // Writing into a String never fails
+        let _ = write!(painted, "{}", gradient.paint(text));
+        painted
+    }
+
+    /// Paint a block of text, like ASCII art, as a single canvas going from `top_left` to `bottom_right`
+    ///
+    /// The color of every character depends on its column and its line: the top right and
+    /// bottom left corners get the color halfway between the two, whatever the length of the lines.
+    ///
+    /// # Params
+    /// - `text`: The lines of the block
+    /// - `top_left`: The color of the first character of the first line
+    /// - `bottom_right`: The color at the last column of the last line
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// let logo = " /\\_/\\\n( o.o )\n > ^ <";
+    /// println!("{}", ColorPrint::gradient_block(logo, (255, 0, 128), (0, 128, 255)));
+    /// ```
+    pub fn gradient_block(text: &str, top_left: impl Into<Color>, bottom_right: impl Into<Color>) -> String {
+        let gradient = Gradient::new(&[top_left.into(), bottom_right.into()]);
+        Self::gradient_with(text, &gradient.direction(crate::GradientDirection::Diagonal))
+    }
+
+    /// Paint the text with a symmetric gradient: `edge` at both ends and `center` in the middle
+    ///
+    /// Every character gets its own color and the colors mirror around the center, so
+    /// odd lengths have a single center character and even lengths a center pair.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `edge`: The color at both ends of the text
+    /// - `center`: The color in the middle of the text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// println!("{}", ColorPrint::gradient_mirror("POLYCHROME", (255, 0, 0), (0, 0, 255)));
+    /// ```
+    pub fn gradient_mirror(text: &str, edge: impl Into<Color>, center: impl Into<Color>) -> String {
+        let gradient = Gradient::new(&[edge.into(), center.into()]);
+        let len = graphemes(text).count();
+        let half = len.saturating_sub(1) / 2;
+
+        // Fold the position around the center so both halves get the same colors
+        paint_chars(text, |i| {
+            let distance = i.min(len - 1 - i);
+            gradient.at(if half == 0 { 0.0 } else { distance as f32 / half as f32 })
+        })
+    }
+
+    /// Paint the text with a gradient whose progression is shaped by an easing function
+    ///
+    /// The linear position of every character (0.0 at the first one, 1.0 at the last one)
+    /// goes through `ease` before interpolating between `start` and `end`.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `start`: The color of the first character
+    /// - `end`: The color of the last character
+    /// - `ease`: Maps the linear position to the interpolation factor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// // Ease-in: stays close to the start color for longer
+    /// println!("{}", ColorPrint::gradient_eased("Hello, world!", (255, 0, 0), (0, 0, 255), |t| t * t));
+    /// ```
+    pub fn gradient_eased(
+        text: &str,
+        start: impl Into<Color>,
+        end: impl Into<Color>,
+        ease: impl Fn(f32) -> f32,
+    ) -> String {
+        let gradient = Gradient::new(&[start.into(), end.into()]);
+        let last = graphemes(text).count().saturating_sub(1);
 
-impl<'a> std::fmt::Display for ColorPrint<'a> {
-    /// Print the text with color and underline style
-    /// 
+        paint_chars(text, |i| gradient.at(ease(if last == 0 { 0.0 } else { i as f32 / last as f32 })))
+    }
+
+    /// Paint the text with a gradient perturbed by seeded random noise, for a "glitch" look
+    ///
+    /// Every channel of every character is moved by up to `amount` from its gradient color.
+    /// The same `seed` always produces the same output.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `start`: The color of the first character
+    /// - `end`: The color of the last character
+    /// - `seed`: The seed of the noise
+    /// - `amount`: The maximum change of every channel, `0` gives the plain gradient
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
-    /// use color_print::ColorPrintExt;
-    /// 
-    /// println!("{}", "Hello, world!".color(255, 0, 0).underline(None)); // Color and underline
-    /// println!("{}", "Hello, world!".color(0, 0, 255).underline(Some("stripe"))); // Color and striped 
+    /// use polychrome::ColorPrint;
+    ///
+    /// println!("{}", ColorPrint::gradient_noisy("Hello, world!", (255, 0, 0), (0, 0, 255), 42, 40));
     /// ```
+    pub fn gradient_noisy(
+        text: &str,
+        start: impl Into<Color>,
+        end: impl Into<Color>,
+        seed: u64,
+        amount: u8,
+    ) -> String {
+        let (start, end) = (start.into(), end.into());
+        let last = graphemes(text).count().saturating_sub(1);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let amount = amount as i16;
+        let mut noise = |channel: u8| (channel as i16 + rng.gen_range(-amount..=amount)).clamp(0, 255) as u8;
 
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let color_code = match self.1 {
-            Some((r, g, b)) => format!("\x1b[38;2;{};{};{}m", r, g, b),
-            None => "".to_string(),
-        };
-        let reset_code = "\x1b[0m";
-        
-        let underline_code = match &self.2 {
-            UnderlineStyle::String(style) => match style.as_str() {
-                "normal" => "\x1b[4m",
-                "stripe" => "\x1b[9m",
-                _ => "",
-            },
-            UnderlineStyle::None => "\x1b[4m",
-        };
+        paint_chars(text, |i| {
+            let t = if last == 0 { 0.0 } else { i as f32 / last as f32 };
+            let Color { r, g, b } = Interpolation::Rgb.mix(start, end, t);
+            Color::rgb(noise(r), noise(g), noise(b))
+        })
+    }
+
+    /// Paint the text in stripes of `stripe_width` characters, cycling through `colors`
+    ///
+    /// Unlike the gradients the colors are not interpolated, giving a barber-pole look.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `colors`: The colors of the stripes, the text is left plain if it is empty
+    /// - `stripe_width`: The number of characters of every stripe
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// println!("{}", ColorPrint::gradient_striped("Hello, world!", &[(255, 0, 0), (255, 255, 255)], 2));
+    /// ```
+    pub fn gradient_striped<C: Into<Color> + Copy>(text: &str, colors: &[C], stripe_width: usize) -> String {
+        Self::polychrome_with(text, colors, stripe_width, Repeat::Cycle)
+    }
+
+    /// Paint every character of the text with the next color of the rainbow
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// println!("{}", ColorPrint::polychrome("Hello, world!"));
+    /// ```
+    pub fn polychrome(text: &str) -> String {
+        Self::polychrome_with(text, crate::gradients::Preset::Rainbow.colors(), 1, Repeat::Cycle)
+    }
+
+    /// Paint the text in chunks of characters taking their colors from `colors`
+    ///
+    /// Like [`ColorPrint::polychrome`] with your own colors, e.g. the brand colors of a company
+    /// or two alternating colors.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `colors`: The colors of the chunks, the text is left plain if it is empty
+    /// - `chunk_size`: The number of characters of every chunk
+    /// - `repeat`: How the colors are repeated along the text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, Repeat};
+    ///
+    /// let brand = [(0, 82, 204), (255, 171, 0), (54, 179, 126)];
+    /// println!("{}", ColorPrint::polychrome_with("Hello, world!", &brand, 2, Repeat::Mirror));
+    /// ```
+    pub fn polychrome_with<C: Into<Color> + Copy>(
+        text: &str,
+        colors: &[C],
+        chunk_size: usize,
+        repeat: Repeat,
+    ) -> String {
+        if colors.is_empty() {
+            return text.to_string();
+        }
+        let chunk_size = chunk_size.max(1);
+        let chunks = graphemes(text).count().div_ceil(chunk_size);
+
+        paint_chars(text, |i| colors[repeat.color_index(i / chunk_size, chunks, colors.len())].into())
+    }
+
+    /// Paint every word or every line of the text with the next color of `colors`
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `colors`: The colors, the text is left plain if it is empty
+    /// - `unit`: The part of the text taking a single color
+    /// - `repeat`: How the colors are repeated along the text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, ColorUnit, Repeat};
+    ///
+    /// let colors = [(255, 0, 0), (255, 255, 0), (0, 200, 255)];
+    /// println!("{}", ColorPrint::polychrome_by("one color per word", &colors, ColorUnit::Word, Repeat::Cycle));
+    /// ```
+    pub fn polychrome_by<C: Into<Color> + Copy>(text: &str, colors: &[C], unit: ColorUnit, repeat: Repeat) -> String {
+        if colors.is_empty() {
+            return text.to_string();
+        }
+        let count = TextLayout::new(text).units(unit);
+        let mut positions = CharPositions::default();
+        let units: Vec<usize> = graphemes(text)
+            .map(|grapheme| positions.position_of(grapheme).unit_index(unit))
+            .collect();
+
+        paint_chars(text, |i| colors[repeat.color_index(units[i], count, colors.len())].into())
+    }
+
+    /// Return frame number `frame` of a rainbow scrolling along the text
+    ///
+    /// Printing the frames one after the other, e.g. every 50 ms over the same line,
+    /// animates the rainbow; the colors move by one character every 4 frames.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `frame`: The index of the frame, the animation loops forever
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polychrome::ColorPrint;
+    /// use std::io::Write;
+    ///
+    /// for frame in 0..100 {
+    ///     print!("\r{}", ColorPrint::polychrome_frame("Loading...", frame));
+    ///     std::io::stdout().flush().unwrap();
+    ///     std::thread::sleep(std::time::Duration::from_millis(50));
+    /// }
+    /// ```
+    pub fn polychrome_frame(text: &str, frame: usize) -> String {
+        Self::polychrome_frame_with(text, crate::gradients::Preset::Rainbow.colors(), frame, 4)
+    }
+
+    /// Return frame number `frame` of `colors` scrolling along the text
+    ///
+    /// Frame 0 is the same as [`ColorPrint::polychrome`] with `colors`. Every next frame moves the
+    /// colors a fraction of a character forward, blending the neighboring colors, so the whole
+    /// animation loops every `colors.len() * steps` frames.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `colors`: The colors, the text is left plain if it is empty
+    /// - `frame`: The index of the frame
+    /// - `steps`: The number of frames to move the colors by one character, `1` gives no blending
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// let colors = [(255, 0, 0), (0, 255, 0), (0, 0, 255)];
+    /// println!("{}", ColorPrint::polychrome_frame_with("Hello, world!", &colors, 7, 8));
+    /// ```
+    pub fn polychrome_frame_with<C: Into<Color> + Copy>(
+        text: &str,
+        colors: &[C],
+        frame: usize,
+        steps: usize,
+    ) -> String {
+        if colors.is_empty() {
+            return text.to_string();
+        }
+        let steps = steps.max(1);
+        let period = colors.len() * steps;
+        let shift = period - frame % period;
+
+        paint_chars(text, |i| {
+            let position = (i % colors.len() * steps + shift) % period;
+            let (index, step) = (position / steps, position % steps);
+            let (from, to) = (colors[index].into(), colors[(index + 1) % colors.len()].into());
+            Interpolation::Rgb.mix(from, to, step as f32 / steps as f32)
+        })
+    }
+
+    /// Paint the text with a color pulsing between `color_a` and `color_b` over `period_ms`
+    ///
+    /// The color depends on the current time, so rendering it repeatedly gives a slow pulse:
+    /// `color_a` at the start of every period and `color_b` at its middle.
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `color_a`: The color at the start of the period
+    /// - `color_b`: The color at the middle of the period
+    /// - `period_ms`: The duration of a whole pulse in milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::ColorPrint;
+    ///
+    /// println!("{}", ColorPrint::gradient_pulsing("Loading...", (255, 0, 0), (0, 0, 255), 2000));
+    /// ```
+    pub fn gradient_pulsing(
+        text: &str,
+        color_a: impl Into<Color>,
+        color_b: impl Into<Color>,
+        period_ms: u64,
+    ) -> String {
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or(0);
+
+        Self::gradient_pulsing_at(text, color_a, color_b, period_ms, now_ms)
+    }
+
+    /// Same as [`ColorPrint::gradient_pulsing`] but at the given time instead of the current one
+    ///
+    /// # Params
+    /// - `now_ms`: The time in milliseconds used to compute the color
+    pub fn gradient_pulsing_at(
+        text: &str,
+        color_a: impl Into<Color>,
+        color_b: impl Into<Color>,
+        period_ms: u64,
+        now_ms: u64,
+    ) -> String {
+        let color = Interpolation::Rgb.mix(color_a.into(), color_b.into(), pulse_factor(period_ms, now_ms));
+        paint_chars(text, |_| color)
+    }
+
+    /// Paint every character with the color returned by `color_at`, for any effect not built in
+    ///
+    /// # Params
+    /// - `text`: The text to paint
+    /// - `color_at`: Called with the index and the character of every character in order, see
+    ///   [`utils::graphemes`](crate::utils::graphemes), a cluster gives its first `char`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrint};
+    ///
+    /// // Digits in yellow, the rest fading from white to gray
+    /// let painted = ColorPrint::color_map("build 42 done", |i, c| {
+    ///     if c.is_ascii_digit() { Color::YELLOW } else { Color::WHITE.darken(i as f32 * 0.03) }
+    /// });
+    /// println!("{}", painted);
+    /// ```
+    pub fn color_map(text: &str, mut color_at: impl FnMut(usize, char) -> Color) -> String {
+        let mut chars = graphemes(text).map(|grapheme| grapheme.chars().next().unwrap_or_default());
+        paint_chars(text, |i| color_at(i, chars.next().unwrap_or_default()))
+    }
+}
+
+/// Factor of a triangle wave going from 0.0 to 1.0 and back to 0.0 over `period_ms`
+#[cfg(feature = "ansi")]
+fn pulse_factor(period_ms: u64, now_ms: u64) -> f32 {
+    let period_ms = period_ms.max(1);
+    let phase = (now_ms % period_ms) as f32 / period_ms as f32;
+    1.0 - (2.0 * phase - 1.0).abs()
+}
+
+/// Return the capacity reserved for a painted text: the text and a color code every few bytes
+#[cfg(feature = "ansi")]
+fn painted_capacity(text: &str) -> usize {
+    text.len() * 4 + 16
+}
+
+/// Paint every character with the color returned by `color_at` for its index
+///
+/// The characters are the items of [`graphemes`], so a cluster never gets two colors.
+/// The colors are written in the global color mode, and unless turned off with
+/// [`set_compact_colors`](crate::utils::set_compact_colors) the color code is only emitted when
+/// it changes, so runs of characters sharing a color don't repeat the same escape sequence.
+#[cfg(feature = "ansi")]
+pub(crate) fn paint_chars(text: &str, mut color_at: impl FnMut(usize) -> Color) -> String {
+    let mode = ColorChoice::global().resolve(None);
+    if text.is_empty() || mode == ColorMode::None {
+        return text.to_string();
+    }
 
-        write!(f, "{}{}{}{}", color_code, underline_code, self.0, reset_code)
+    let compact = crate::utils::compact_colors();
+    let mut result = String::with_capacity(painted_capacity(text));
+    let mut previous = None;
+    for (i, grapheme) in graphemes(text).enumerate() {
+        let param = mode.fg_param(color_at(i));
+        if param.is_some() && (previous != param || !compact) {
+            if let Some(param) = param {
+                // Writing into a String never fails
+                let _ = std::fmt::Write::write_fmt(&mut result, format_args!("\x1b[{}m", param));
+            }
+            previous = param;
+        }
+        result.push_str(grapheme);
     }
+    result.push_str(crate::utils::reset_code());
+    result
 }
 
 pub trait ColorPrintExt {
@@ -57,54 +1434,88 @@ pub trait ColorPrintExt {
     /// # Example
     /// 
     /// ```
-    /// use color_print::ColorPrintExt;
+    /// use polychrome::ColorPrintExt;
     /// 
     /// println!("{}", "Hello, world!".color(255, 0, 0)); // Red color
     /// ```
     /// 
     fn color<'a>(&'a self, r: u8, g: u8, b: u8) -> ColorPrint<'a>;
+    /// Set the color of the text from a [`Color`], an [`AnsiColor`](crate::AnsiColor) or a `(r, g, b)` tuple
+    ///
+    /// # Params
+    /// - `color`: The color of the text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{AnsiColor, Color, ColorPrintExt};
+    ///
+    /// println!("{}", "Hello, world!".fg(Color::RED)); // Red color
+    /// println!("{}", "Hello, world!".fg((0, 0, 255))); // Blue color
+    /// println!("{}", "Hello, world!".fg(AnsiColor::Green)); // The green of the terminal theme
+    /// ```
+    fn fg<'a>(&'a self, color: impl Into<TermColor>) -> ColorPrint<'a>;
     /// Set the underline style of the text
     /// 
     /// # Params
-    /// - `style`: The underline style, can be `None`, `stripe`
+    /// - `style`: The underline style, `None` for a normal underline. `stripe` is a deprecated
+    ///   alias of [`ColorPrint::strikethrough`], which can be combined with an underline
     /// 
     /// # Example
     /// 
     /// ```
-    /// use color_print::ColorPrintExt;
+    /// use polychrome::ColorPrintExt;
     /// 
     /// println!("{}", "Hello, world!".underline(None)); // Normal underline
-    /// println!("{}", "Hello, world!".underline(Some("stripe"))); // Striped underline
+    /// println!("{}", "Hello, world!".underline(None).strikethrough()); // Underline and strikethrough
     /// ```
     fn underline<'a>(&'a self, style: Option<&str>) -> ColorPrint<'a>;
 }
 
-impl<'a> ColorPrintExt for &'a str {
-    fn color<'b>(&'b self, r: u8, g: u8, b: u8) -> ColorPrint<'b> {
-        ColorPrint(self, Some((r, g, b)), UnderlineStyle::None)
-    }
+/// Borrow the text with the style given by [`ColorPrintExt::fg`] to the string types
+fn fg_text(text: &str, color: impl Into<TermColor>) -> ColorPrint<'_> {
+    ColorPrint(Cow::Borrowed(text), Style::new().fg(color).underline(None))
+}
 
-    fn underline<'b>(&'b self, style: Option<&str>) -> ColorPrint<'b> {
-        let underline_style = match style {
-            Some("stripe") => UnderlineStyle::String("stripe".to_string()),
-            _ => UnderlineStyle::None,
-        };
-        ColorPrint(self, None, underline_style)
-    }
+/// Borrow the text with the style given by [`ColorPrintExt::underline`] to the string types
+fn underline_text<'a>(text: &'a str, style: Option<&str>) -> ColorPrint<'a> {
+    ColorPrint(Cow::Borrowed(text), Style::new().underline(style.filter(|style| *style == "stripe")))
+}
+
+/// Implement [`ColorPrintExt`] for string types, borrowing their text
+macro_rules! impl_color_print_ext {
+    ($($text:ty),*) => {
+        $(
+            impl ColorPrintExt for $text {
+                fn color<'b>(&'b self, r: u8, g: u8, b: u8) -> ColorPrint<'b> {
+                    self.fg(Color::rgb(r, g, b))
+                }
+
+                fn fg<'b>(&'b self, color: impl Into<TermColor>) -> ColorPrint<'b> {
+                    fg_text(self, color)
+                }
+
+                fn underline<'b>(&'b self, style: Option<&str>) -> ColorPrint<'b> {
+                    underline_text(self, style)
+                }
+            }
+        )*
+    };
 }
 
+impl_color_print_ext!(&str, String, &String, Cow<'_, str>);
+
 impl<'a> ColorPrintExt for ColorPrint<'a> {
     fn color<'b>(&'b self, r: u8, g: u8, b: u8) -> ColorPrint<'b> {
-        ColorPrint(self.0, Some((r, g, b)), self.2.clone())
+        self.fg(Color::rgb(r, g, b))
+    }
+
+    fn fg<'b>(&'b self, color: impl Into<TermColor>) -> ColorPrint<'b> {
+        self.restyle(&*self.0).map_style(|style| style.fg(color))
     }
 
     fn underline<'b>(&'b self, style: Option<&str>) -> ColorPrint<'b> {
-        let underline_style = match style {
-            Some("upper") => UnderlineStyle::String("upper".to_string()),
-            Some("stripe") => UnderlineStyle::String("stripe".to_string()),
-            _ => UnderlineStyle::None,
-        };
-        ColorPrint(self.0, self.1, underline_style)
+        self.restyle(&*self.0).map_style(|current| current.underline(style))
     }
 }
 
@@ -112,10 +1523,520 @@ impl<'a> ColorPrintExt for ColorPrint<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::AnsiColor;
 
     #[test]
     fn test_color_underline() {
         println!("{}", "Hello, world!".color(255, 0, 0).underline(None)); // Con color y subrayado
         println!("{}", "Hello, world!".color(0, 0, 255).underline(Some("stripe"))); // Con color y subrayado superior
     }
-}
+
+    #[cfg(feature = "ansi")]
+    /// Split painted output into the color code of every character
+    fn char_colors(painted: &str) -> Vec<&str> {
+        let mut colors = Vec::new();
+        let mut current = "";
+        let mut rest = painted;
+        while let Some(c) = rest.chars().next() {
+            if let Some(code) = rest.strip_prefix("\x1b[") {
+                let end = code.find('m').unwrap();
+                current = code[..end].trim_start_matches("38;2;");
+                rest = &code[end + 1..];
+            } else {
+                colors.push(current);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        colors
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_gradient_mirror() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        for text in ["POLYCHROME", "RAINBOW"] {
+            let painted = ColorPrint::gradient_mirror(text, (255, 0, 0), (0, 0, 255));
+            let colors = char_colors(&painted);
+            let mut reversed = colors.clone();
+            reversed.reverse();
+
+            assert_eq!(colors.len(), text.len());
+            assert_eq!(colors, reversed);
+            assert_eq!(colors[0], "255;0;0");
+            assert_eq!(colors[text.len() / 2], "0;0;255");
+        }
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_paint_chars_skips_repeated_colors() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let painted = ColorPrint::gradient_mirror("ABCD", (255, 0, 0), (0, 0, 255));
+
+        assert_eq!(painted.matches("\x1b[38;2;").count(), 3);
+        assert_eq!(char_colors(&painted), ["255;0;0", "0;0;255", "0;0;255", "255;0;0"]);
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_paint_chars_color_modes() {
+        let colors = [Color::RED, Color::rgb(250, 0, 0), Color::rgb(0, 0, 255)];
+        let mode = ColorMode::global_guard(ColorMode::None);
+        assert_eq!(paint_chars("abc", |i| colors[i]), "abc");
+        drop(mode);
+
+        // Both reds are the same color with 16 colors, so the second one has no escape code
+        let _mode = ColorMode::global_guard(ColorMode::Ansi16);
+        assert_eq!(paint_chars("abc", |i| colors[i]), "\x1b[91mab\x1b[34mc\x1b[0m");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_underline_sgr_code() {
+        assert_eq!(UnderlineStyle::None.sgr_code(), "\x1b[4m");
+        assert_eq!(UnderlineStyle::String("normal".to_string()).sgr_code(), "\x1b[4m");
+        assert_eq!(UnderlineStyle::String("stripe".to_string()).sgr_code(), "\x1b[9m");
+        assert_eq!(UnderlineStyle::String("upper".to_string()).sgr_code(), "");
+
+        let text = "Hello".underline(Some("stripe"));
+        assert!(text.style().has_text_style(TextStyle::Strikethrough));
+        assert_eq!(text.underline_style(), None);
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_gradient_eased() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let linear = ColorPrint::gradient_eased("abcde", (255, 0, 0), (0, 0, 255), |t| t);
+        let eased = ColorPrint::gradient_eased("abcde", (255, 0, 0), (0, 0, 255), |t| t * t);
+
+        assert_eq!(char_colors(&linear)[2], "128;0;128");
+        assert_eq!(char_colors(&eased)[2], "191;0;64");
+        assert_eq!(char_colors(&eased)[4], "0;0;255");
+    }
+
+    #[test]
+    #[cfg(not(feature = "ansi"))]
+    fn test_display_without_ansi() {
+        let text = "Hello".color(255, 0, 0).underline(Some("stripe")).to_string();
+        assert_eq!(text, "Hello");
+    }
+
+    #[test]
+    fn test_invert_if_dark() {
+        let near_black = ColorPrint::from("a").map_style(|style| style.fg(Color::rgb(10, 10, 20)));
+        let near_white = ColorPrint::from("a").map_style(|style| style.fg(Color::rgb(240, 240, 230)));
+
+        crate::utils::set_dark_background(true);
+        assert_eq!(near_black.clone().invert_if_dark().style().fg, Some(TermColor::Rgb(Color::rgb(245, 245, 235))));
+        assert_eq!(near_white.clone().invert_if_dark(), near_white);
+        crate::utils::set_dark_background(false);
+        assert_eq!(near_black.clone().invert_if_dark(), near_black);
+        assert_eq!(near_white.invert_if_dark().style().fg, Some(TermColor::Rgb(Color::rgb(15, 15, 25))));
+
+        let theme = ColorPrint::from("a").map_style(|style| style.fg(crate::AnsiColor::Black));
+        assert_eq!(theme.clone().invert_if_dark(), theme);
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_codes_wrap_text_with_tabs() {
+        let text = "name\tvalue\t".color(255, 0, 0).mode(ColorMode::TrueColor).to_string();
+        assert_eq!(text, "\x1b[38;2;255;0;0;4mname\tvalue\t\x1b[0m");
+
+        let gradient = Style::new().gradient(Color::RED, Color::BLUE).mode(ColorMode::TrueColor);
+        let row = gradient.apply("ab\tcd\te\t\tfg").tab_safe().to_string();
+        assert!(row.contains('\t'));
+        assert!(!row.contains("m\t") && !row.contains("\t\x1b"));
+        assert_eq!(crate::utils::strip_ansi(&row), "ab\tcd\te\t\tfg");
+
+        let row = gradient.apply("ab\tcd").to_string();
+        assert!(row.contains("m\t") || row.contains("\t\x1b"));
+        assert_eq!(ColorPrint::from("plain").tab_safe().to_string(), "plain");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_noisy() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let first = ColorPrint::gradient_noisy("Hello, world!", (255, 0, 0), (0, 0, 255), 7, 60);
+        let second = ColorPrint::gradient_noisy("Hello, world!", (255, 0, 0), (0, 0, 255), 7, 60);
+        let plain = ColorPrint::gradient_noisy("Hello, world!", (255, 0, 0), (0, 0, 255), 7, 0);
+
+        assert_eq!(first, second);
+        assert_ne!(first, plain);
+        assert_eq!(plain, ColorPrint::gradient_eased("Hello, world!", (255, 0, 0), (0, 0, 255), |t| t));
+    }
+
+    #[test]
+    fn test_render_debug() {
+        let debug = "Hello".color(255, 0, 0).render_debug();
+
+        assert!(debug.contains("[fg:255,0,0]"));
+        assert_eq!(debug, "[fg:255,0,0][underline]Hello[reset]");
+        assert_eq!("Hi".fg(AnsiColor::Red).render_debug(), "[fg:red][underline]Hi[reset]");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_striped() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let painted = ColorPrint::gradient_striped("abcdefg", &[(255, 0, 0), (0, 0, 255)], 3);
+
+        assert_eq!(
+            char_colors(&painted),
+            ["255;0;0", "255;0;0", "255;0;0", "0;0;255", "0;0;255", "0;0;255", "255;0;0"]
+        );
+        assert_eq!(ColorPrint::gradient_striped::<Color>("abc", &[], 3), "abc");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_pulsing_at() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        assert_eq!(pulse_factor(1000, 0), 0.0);
+        assert_eq!(pulse_factor(1000, 250), 0.5);
+        assert_eq!(pulse_factor(1000, 500), 1.0);
+        assert_eq!(pulse_factor(1000, 1750), 0.5);
+
+        let start = ColorPrint::gradient_pulsing_at("ab", (255, 0, 0), (0, 0, 255), 1000, 3000);
+        let middle = ColorPrint::gradient_pulsing_at("ab", (255, 0, 0), (0, 0, 255), 1000, 3500);
+        assert_eq!(start, "\x1b[38;2;255;0;0mab\x1b[0m");
+        assert_eq!(middle, "\x1b[38;2;0;0;255mab\x1b[0m");
+    }
+
+    #[test]
+    fn test_fg_accepts_color() {
+        assert_eq!("Hi".fg(Color::RED).render_debug(), "Hi".color(255, 0, 0).render_debug());
+        assert_eq!("Hi".fg((0, 0, 255)).render_debug(), "[fg:0,0,255][underline]Hi[reset]");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_in() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let rgb = ColorPrint::gradient("abc", Color::RED, Color::LIME);
+        let oklab = ColorPrint::gradient_in("abc", Color::RED, Color::LIME, Interpolation::Oklab);
+
+        assert_eq!(char_colors(&rgb), ["255;0;0", "128;128;0", "0;255;0"]);
+        assert_eq!(char_colors(&oklab)[0], "255;0;0");
+        assert_eq!(char_colors(&oklab)[2], "0;255;0");
+        assert_ne!(char_colors(&oklab)[1], "128;128;0");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_ansi256_mode() {
+        let text = "Hi".color(255, 136, 0).mode(ColorMode::Ansi256).to_string();
+        assert_eq!(text, "\x1b[38;5;208;4mHi\x1b[0m");
+
+        let text = "Hi".color(255, 136, 0).mode(ColorMode::TrueColor).to_string();
+        assert_eq!(text, "\x1b[38;2;255;136;0;4mHi\x1b[0m");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_ansi16_mode() {
+        assert_eq!(ColorMode::Ansi16.fg_code(Color::rgb(200, 0, 0)), "\x1b[31m");
+        assert_eq!(ColorMode::Ansi16.fg_code(Color::rgb(250, 250, 250)), "\x1b[97m");
+
+        let text = "Hi".color(0, 0, 230).mode(ColorMode::Ansi16).to_string();
+        assert_eq!(text, "\x1b[34;4mHi\x1b[0m");
+
+        let text = "Hi".color(0, 0, 230).mode(ColorMode::None).to_string();
+        assert_eq!(text, "Hi");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_fg_ansi_color() {
+        let text = "Hi".fg(AnsiColor::BrightBlue).mode(ColorMode::TrueColor).to_string();
+        assert_eq!(text, "\x1b[94;4mHi\x1b[0m");
+
+        let text = "Hi".fg(AnsiColor::Red).mode(ColorMode::Ansi256).to_string();
+        assert_eq!(text, "\x1b[31;4mHi\x1b[0m");
+    }
+
+    fn owned_status(count: usize) -> ColorPrint<'static> {
+        ColorPrint::from(format!("{} files", count)).map_style(|style| style.fg(Color::RED).underline(Some("stripe")))
+    }
+
+    #[test]
+    fn test_owned_color_print() {
+        let status = owned_status(3).mode(ColorMode::TrueColor);
+        assert_eq!(status.render_debug(), "[fg:255,0,0][strikethrough]3 files[reset]");
+
+        let text = String::from("borrowed");
+        let owned = ColorPrint::from(text.as_str()).color(0, 0, 255).into_owned();
+        drop(text);
+        assert_eq!(owned.render_debug(), "[fg:0,0,255]borrowed[reset]");
+        assert_eq!(ColorPrint::from("plain").render_debug(), "plain[reset]");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_underline_color() {
+        let text = "error".underline(None).underline_color(255, 0, 0);
+        assert_eq!(text.render_debug(), "[underline][underline_color:255,0,0]error[reset]");
+        assert_eq!(text.mode(ColorMode::TrueColor).to_string(), "\x1b[4;58;2;255;0;0merror\x1b[0m");
+        assert_eq!(ColorMode::Ansi256.underline_color_code(Color::RED), "\x1b[58;5;196m");
+        assert_eq!(ColorMode::Ansi16.underline_color_code(Color::RED), "\x1b[58;5;9m");
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!("東京".color(255, 0, 0).display_width(), 4);
+        assert_eq!(ColorPrint::from("abc").underline(None).display_width(), 3);
+    }
+
+    #[test]
+    fn test_when_unless() {
+        assert_eq!("ok".color(0, 255, 0).when(false).render_debug(), "ok[reset]");
+        assert_eq!("ok".color(0, 255, 0).unless(false).render_debug(), "[fg:0,255,0][underline]ok[reset]");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_color_choice_resolve() {
+        let _mode = ColorMode::global_guard(ColorMode::Ansi256);
+        for mode in [ColorMode::TrueColor, ColorMode::Ansi16, ColorMode::None] {
+            assert_eq!(ColorChoice::Auto.resolve(Some(mode)), mode);
+            assert_eq!(ColorChoice::Always.resolve(Some(mode)), mode);
+            assert_eq!(ColorChoice::Never.resolve(Some(mode)), ColorMode::None);
+        }
+        assert_eq!(ColorChoice::Auto.resolve(None), ColorMode::Ansi256);
+        assert_eq!(ColorChoice::Always.resolve(None), ColorMode::Ansi256);
+        assert_eq!(ColorChoice::Never.resolve(None), ColorMode::None);
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_write_to_and_render_into() {
+        let text = "hi".color(255, 0, 0).mode(ColorMode::TrueColor);
+
+        let mut bytes = Vec::new();
+        text.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes, text.to_string().as_bytes());
+
+        let mut buffer = String::from(">");
+        text.render_into(&mut buffer);
+        assert_eq!(buffer, format!(">{}", text));
+    }
+
+    #[test]
+    fn test_color_print_eq_hash() {
+        use std::collections::HashSet;
+
+        let owned = ColorPrint::from(String::from("ok")).map_style(|style| style.color(0, 255, 0));
+        let borrowed = ColorPrint::from("ok").map_style(|style| style.color(0, 255, 0));
+        assert_eq!(owned, borrowed);
+        assert_ne!(borrowed.clone(), ColorPrint::from("ok").map_style(|style| style.color(0, 255, 1)));
+
+        let messages: HashSet<ColorPrint> = [owned, borrowed, ColorPrint::from("ok")].into_iter().collect();
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[test]
+    fn test_owned_strings_ext() {
+        let owned = String::from("owned");
+        let borrowed = &owned;
+        let cow: Cow<str> = Cow::Owned(String::from("cow"));
+
+        assert_eq!(owned.color(255, 0, 0), "owned".color(255, 0, 0));
+        assert_eq!(borrowed.fg(AnsiColor::Red), "owned".fg(AnsiColor::Red));
+        assert_eq!(cow.underline(Some("stripe")), "cow".underline(Some("stripe")));
+        assert_eq!(format!("{}", owned.color(0, 0, 255).bold().into_owned()), format!("{}", "owned".color(0, 0, 255).bold()));
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_underline_shapes() {
+        let curly = UnderlineStyle::String("curly".to_string());
+        assert_eq!(curly.sgr_code(), "\x1b[4:3m");
+        assert_eq!(UnderlineStyle::String("double".to_string()).sgr_code(), "\x1b[4:2m");
+        assert_eq!(UnderlineStyle::String("dashed".to_string()).sgr_code(), "\x1b[4:5m");
+        assert!(curly.is_shape());
+        assert!(!UnderlineStyle::String("stripe".to_string()).is_shape());
+        assert_eq!(curly.degrade(), UnderlineStyle::None);
+        assert_eq!(UnderlineStyle::String("stripe".to_string()).degrade(), UnderlineStyle::String("stripe".to_string()));
+
+        let text = "shapes";
+        let expected = if crate::utils::supports_underline_styles() { "\x1b[4:4m" } else { "\x1b[4m" };
+        let printed = text.underline(Some("dotted")).mode(ColorMode::TrueColor).to_string();
+        assert_eq!(printed, format!("{}shapes\x1b[0m", expected));
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_double_underline_code() {
+        assert_eq!(DoubleUnderline::Extended.sgr_param(), "4:2");
+        assert_eq!(DoubleUnderline::Legacy.sgr_param(), "21");
+        assert_eq!(DoubleUnderline::Single.sgr_param(), "4");
+
+        let text = "total";
+        let printed = text.underline(Some("double")).bold().mode(ColorMode::TrueColor).to_string();
+        assert_eq!(printed, format!("\x1b[1;{}mtotal\x1b[0m", DoubleUnderline::global().sgr_param()));
+    }
+
+    #[test]
+    fn test_text_accessors() {
+        let text = "日本 text";
+        let styled = text.color(255, 0, 0).bold();
+        assert_eq!(styled.text(), "日本 text");
+        assert_eq!(styled.plain(), "日本 text");
+        assert_eq!(styled.len(), 11);
+        assert!(!styled.is_empty());
+        assert!(ColorPrint::from("").is_empty());
+
+        let nested = ColorPrint::from("a \x1b[1mbold\x1b[0m b");
+        assert_eq!(nested.text(), "a \x1b[1mbold\x1b[0m b");
+        assert_eq!(nested.plain(), "a bold b");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_background() {
+        let text = "bg";
+        let printed = ColorPrint::from(text).map_style(|style| style.color(255, 0, 0)).bg(Color::BLUE);
+        let printed = printed.mode(ColorMode::TrueColor);
+        assert_eq!(printed.to_string(), "\x1b[38;2;255;0;0;48;2;0;0;255mbg\x1b[0m");
+
+        let printed = ColorPrint::from(text).bg((255, 0, 0)).mode(ColorMode::Ansi256);
+        assert_eq!(printed.to_string(), "\x1b[48;5;196mbg\x1b[0m");
+        assert_eq!(ColorPrint::from(text).bg(AnsiColor::Green).render_debug(), "[bg:green]bg[reset]");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_ansi_color_shortcuts() {
+        let printed = ColorPrint::from("err").red().on_black().bold().mode(ColorMode::TrueColor);
+        assert_eq!(printed.to_string(), "\x1b[31;40;1merr\x1b[0m");
+        assert_eq!(ColorPrint::from("ok").bright_green().render_debug(), "[fg:bright_green]ok[reset]");
+        assert_eq!(ColorPrint::from("ok").on_bright_white().render_debug(), "[bg:bright_white]ok[reset]");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_gradient_multi() {
+        let mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let painted = ColorPrint::gradient_multi("abcde", &[Color::RED, Color::LIME, Color::BLUE]);
+        assert_eq!(char_colors(&painted), ["255;0;0", "128;128;0", "0;255;0", "0;128;128", "0;0;255"]);
+        assert_eq!(ColorPrint::gradient_multi::<Color>("abc", &[]), "abc");
+
+        let gradient = Gradient::with_stops(&[(0.0, Color::RED), (0.5, Color::RED), (1.0, Color::BLUE)]);
+        let painted = ColorPrint::gradient_with("abcde", &gradient);
+        assert_eq!(char_colors(&painted), ["255;0;0", "255;0;0", "255;0;0", "128;0;128", "0;0;255"]);
+        drop(mode);
+
+        let _mode = ColorMode::global_guard(ColorMode::Ansi16);
+        assert_eq!(ColorPrint::gradient_with("ab", &Gradient::new(&[Color::RED])), "\x1b[91mab\x1b[0m");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_polychrome() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let rainbow = ColorPrint::polychrome("abcdefgh");
+        assert_eq!(char_colors(&rainbow)[0], "255;0;0");
+        assert_eq!(char_colors(&rainbow)[7], "255;0;0");
+
+        let colors = [Color::RED, Color::LIME, Color::BLUE];
+        let painted = ColorPrint::polychrome_with("abcdefgh", &colors, 2, Repeat::Mirror);
+        let expected = ["255;0;0", "255;0;0", "0;255;0", "0;255;0", "0;0;255", "0;0;255", "0;255;0", "0;255;0"];
+        assert_eq!(char_colors(&painted), expected);
+
+        let painted = ColorPrint::polychrome_with("abcdef", &colors, 5, Repeat::Stretch);
+        assert_eq!(char_colors(&painted), ["255;0;0", "255;0;0", "255;0;0", "255;0;0", "255;0;0", "0;255;0"]);
+        assert_eq!(ColorPrint::polychrome_with::<Color>("abc", &[], 1, Repeat::Cycle), "abc");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_gradient_bg() {
+        let mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let painted = ColorPrint::gradient_bg("ab", Color::RED, Color::BLUE, None);
+        assert_eq!(painted, "\x1b[48;2;255;0;0ma\x1b[48;2;0;0;255mb\x1b[0m");
+        drop(mode);
+
+        let _mode = ColorMode::global_guard(ColorMode::Ansi256);
+        let painted = ColorPrint::gradient_bg("ab", Color::RED, Color::BLUE, None);
+        assert_eq!(painted, "\x1b[48;5;196ma\x1b[48;5;21mb\x1b[0m");
+        assert_eq!(ColorPrint::gradient_bg("", Color::RED, Color::BLUE, None), "");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_polychrome_ansi256() {
+        let _mode = ColorMode::global_guard(ColorMode::Ansi256);
+        let orange = crate::gradients::Preset::Rainbow.colors()[1].to_ansi256();
+        let rainbow = ColorPrint::polychrome("ab");
+        assert_eq!(rainbow, format!("\x1b[38;5;196ma\x1b[38;5;{}mb\x1b[0m", orange));
+
+        let painted = ColorPrint::polychrome_with("abc", &[Color::RED, Color::BLUE], 2, Repeat::Cycle);
+        assert_eq!(painted, "\x1b[38;5;196mab\x1b[38;5;21mc\x1b[0m");
+        assert!(!ColorPrint::gradient_mirror("abcd", Color::RED, Color::BLUE).contains("38;2;"));
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_polychrome_by() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let colors = [Color::RED, Color::BLUE];
+        let painted = ColorPrint::polychrome_by("ab cd e", &colors, ColorUnit::Word, Repeat::Cycle);
+        assert_eq!(painted, "\x1b[38;2;255;0;0mab \x1b[38;2;0;0;255mcd \x1b[38;2;255;0;0me\x1b[0m");
+
+        let painted = ColorPrint::polychrome_by("ab\ncd", &colors, ColorUnit::Line, Repeat::Cycle);
+        assert_eq!(painted, "\x1b[38;2;255;0;0mab\n\x1b[38;2;0;0;255mcd\x1b[0m");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_gradient_block() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let block = ColorPrint::gradient_block("ab\nc", Color::BLACK, Color::WHITE);
+        assert_eq!(char_colors(&block), ["0;0;0", "128;128;128", "255;255;255", "128;128;128"]);
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_polychrome_frame() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let text = "abcdefgh";
+        assert_eq!(ColorPrint::polychrome_frame(text, 0), ColorPrint::polychrome(text));
+        assert_eq!(ColorPrint::polychrome_frame(text, 28), ColorPrint::polychrome(text));
+
+        let colors = [Color::RED, Color::BLUE];
+        let painted = ColorPrint::polychrome_frame_with("abc", &colors, 1, 1);
+        assert_eq!(char_colors(&painted), ["0;0;255", "255;0;0", "0;0;255"]);
+        let painted = ColorPrint::polychrome_frame_with("abc", &colors, 1, 2);
+        assert_eq!(char_colors(&painted), ["128;0;128", "128;0;128", "128;0;128"]);
+        assert_eq!(ColorPrint::polychrome_frame_with::<Color>("abc", &[], 3, 2), "abc");
+    }
+
+    #[cfg(all(feature = "ansi", feature = "unicode"))]
+    #[test]
+    fn test_gradient_graphemes() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let text = format!("{family}e\u{301}");
+        let expected = format!("\x1b[38;2;255;0;0m{family}\x1b[38;2;0;0;255me\u{301}\x1b[0m");
+        assert_eq!(ColorPrint::gradient(&text, Color::RED, Color::BLUE), expected);
+        assert_eq!(ColorPrint::polychrome_with(&text, &[Color::RED, Color::BLUE], 1, Repeat::Cycle), expected);
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_color_map() {
+        let _mode = ColorMode::global_guard(ColorMode::TrueColor);
+        let painted = ColorPrint::color_map("a1b2", |_, c| if c.is_ascii_digit() { Color::RED } else { Color::BLUE });
+        assert_eq!(char_colors(&painted), ["0;0;255", "255;0;0", "0;0;255", "255;0;0"]);
+
+        let mut indexes = Vec::new();
+        ColorPrint::color_map("abc", |i, _| {
+            indexes.push(i);
+            Color::WHITE
+        });
+        assert_eq!(indexes, [0, 1, 2]);
+        assert_eq!(ColorPrint::color_map("", |_, _| Color::RED), "");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+// colorblind.rs
+//! Palettes that stay distinguishable with the common color vision deficiencies
+//!
+//! Use [`Color::simulate`] to check other colors.
+//!
+//! # Example
+//!
+//! ```
+//! use polychrome::colors::colorblind;
+//! use polychrome::ColorPrintExt;
+//!
+//! for (i, color) in colorblind::OKABE_ITO.iter().enumerate() {
+//!     println!("{}", format!("series {}", i).as_str().fg(*color));
+//! }
+//! ```
+use crate::Color;
+
+/// The Okabe-Ito palette: black, orange, sky blue, bluish green, yellow, blue, vermillion, reddish purple
+pub const OKABE_ITO: [Color; 8] = [
+    Color::rgb(0, 0, 0),
+    Color::rgb(230, 159, 0),
+    Color::rgb(86, 180, 233),
+    Color::rgb(0, 158, 115),
+    Color::rgb(240, 228, 66),
+    Color::rgb(0, 114, 178),
+    Color::rgb(213, 94, 0),
+    Color::rgb(204, 121, 167),
+];
+
+/// Paul Tol's bright scheme: blue, red, green, yellow, cyan, purple, grey
+pub const TOL_BRIGHT: [Color; 7] = [
+    Color::rgb(68, 119, 170),
+    Color::rgb(238, 102, 119),
+    Color::rgb(34, 136, 51),
+    Color::rgb(204, 187, 68),
+    Color::rgb(102, 204, 238),
+    Color::rgb(170, 51, 119),
+    Color::rgb(187, 187, 187),
+];
+
+/// The IBM Design Library palette: blue, purple, magenta, orange, yellow
+pub const IBM: [Color; 5] = [
+    Color::rgb(100, 143, 255),
+    Color::rgb(120, 94, 240),
+    Color::rgb(220, 38, 127),
+    Color::rgb(254, 97, 0),
+    Color::rgb(255, 176, 0),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorBlindness;
+
+    #[test]
+    fn test_palettes_stay_distinguishable() {
+        for palette in [&OKABE_ITO[..], &TOL_BRIGHT[..], &IBM[..]] {
+            for kind in [ColorBlindness::Protanopia, ColorBlindness::Deuteranopia, ColorBlindness::Tritanopia] {
+                for (i, a) in palette.iter().enumerate() {
+                    for b in &palette[i + 1..] {
+                        assert!(a.simulate(kind).distance(b.simulate(kind)) > 0.03, "{:?} {:?} {:?}", kind, a, b);
+                    }
+                }
+            }
+        }
+    }
+}
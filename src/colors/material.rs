@@ -0,0 +1,32 @@
+// material.rs
+//! The Material Design palette, the primary (500) shade of every hue
+//!
+//! # Example
+//!
+//! ```
+//! use polychrome::colors::material;
+//! use polychrome::ColorPrintExt;
+//!
+//! println!("{}", "Hello, world!".fg(material::DEEP_PURPLE));
+//! ```
+use crate::Color;
+
+pub const RED: Color = Color::rgb(244, 67, 54);
+pub const PINK: Color = Color::rgb(233, 30, 99);
+pub const PURPLE: Color = Color::rgb(156, 39, 176);
+pub const DEEP_PURPLE: Color = Color::rgb(103, 58, 183);
+pub const INDIGO: Color = Color::rgb(63, 81, 181);
+pub const BLUE: Color = Color::rgb(33, 150, 243);
+pub const LIGHT_BLUE: Color = Color::rgb(3, 169, 244);
+pub const CYAN: Color = Color::rgb(0, 188, 212);
+pub const TEAL: Color = Color::rgb(0, 150, 136);
+pub const GREEN: Color = Color::rgb(76, 175, 80);
+pub const LIGHT_GREEN: Color = Color::rgb(139, 195, 74);
+pub const LIME: Color = Color::rgb(205, 220, 57);
+pub const YELLOW: Color = Color::rgb(255, 235, 59);
+pub const AMBER: Color = Color::rgb(255, 193, 7);
+pub const ORANGE: Color = Color::rgb(255, 152, 0);
+pub const DEEP_ORANGE: Color = Color::rgb(255, 87, 34);
+pub const BROWN: Color = Color::rgb(121, 85, 72);
+pub const GREY: Color = Color::rgb(158, 158, 158);
+pub const BLUE_GREY: Color = Color::rgb(96, 125, 139);
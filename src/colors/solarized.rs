@@ -0,0 +1,31 @@
+// solarized.rs
+//! The Solarized palette by Ethan Schoonover
+//!
+//! `BASE03` to `BASE3` are the background and content tones, from darkest to lightest.
+//!
+//! # Example
+//!
+//! ```
+//! use polychrome::colors::solarized;
+//! use polychrome::ColorPrintExt;
+//!
+//! println!("{}", "Hello, world!".fg(solarized::CYAN));
+//! ```
+use crate::Color;
+
+pub const BASE03: Color = Color::rgb(0, 43, 54);
+pub const BASE02: Color = Color::rgb(7, 54, 66);
+pub const BASE01: Color = Color::rgb(88, 110, 117);
+pub const BASE00: Color = Color::rgb(101, 123, 131);
+pub const BASE0: Color = Color::rgb(131, 148, 150);
+pub const BASE1: Color = Color::rgb(147, 161, 161);
+pub const BASE2: Color = Color::rgb(238, 232, 213);
+pub const BASE3: Color = Color::rgb(253, 246, 227);
+pub const YELLOW: Color = Color::rgb(181, 137, 0);
+pub const ORANGE: Color = Color::rgb(203, 75, 22);
+pub const RED: Color = Color::rgb(220, 50, 47);
+pub const MAGENTA: Color = Color::rgb(211, 54, 130);
+pub const VIOLET: Color = Color::rgb(108, 113, 196);
+pub const BLUE: Color = Color::rgb(38, 139, 210);
+pub const CYAN: Color = Color::rgb(42, 161, 152);
+pub const GREEN: Color = Color::rgb(133, 153, 0);
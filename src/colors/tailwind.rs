@@ -0,0 +1,35 @@
+// tailwind.rs
+//! The Tailwind CSS palette, the 500 shade of every color
+//!
+//! # Example
+//!
+//! ```
+//! use polychrome::colors::tailwind;
+//! use polychrome::ColorPrintExt;
+//!
+//! println!("{}", "Hello, world!".fg(tailwind::EMERALD));
+//! ```
+use crate::Color;
+
+pub const SLATE: Color = Color::rgb(100, 116, 139);
+pub const GRAY: Color = Color::rgb(107, 114, 128);
+pub const ZINC: Color = Color::rgb(113, 113, 122);
+pub const NEUTRAL: Color = Color::rgb(115, 115, 115);
+pub const STONE: Color = Color::rgb(120, 113, 108);
+pub const RED: Color = Color::rgb(239, 68, 68);
+pub const ORANGE: Color = Color::rgb(249, 115, 22);
+pub const AMBER: Color = Color::rgb(245, 158, 11);
+pub const YELLOW: Color = Color::rgb(234, 179, 8);
+pub const LIME: Color = Color::rgb(132, 204, 22);
+pub const GREEN: Color = Color::rgb(34, 197, 94);
+pub const EMERALD: Color = Color::rgb(16, 185, 129);
+pub const TEAL: Color = Color::rgb(20, 184, 166);
+pub const CYAN: Color = Color::rgb(6, 182, 212);
+pub const SKY: Color = Color::rgb(14, 165, 233);
+pub const BLUE: Color = Color::rgb(59, 130, 246);
+pub const INDIGO: Color = Color::rgb(99, 102, 241);
+pub const VIOLET: Color = Color::rgb(139, 92, 246);
+pub const PURPLE: Color = Color::rgb(168, 85, 247);
+pub const FUCHSIA: Color = Color::rgb(217, 70, 239);
+pub const PINK: Color = Color::rgb(236, 72, 153);
+pub const ROSE: Color = Color::rgb(244, 63, 94);
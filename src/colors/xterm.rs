@@ -0,0 +1,534 @@
+// xterm.rs
+//! The xterm 256 color palette
+//!
+//! - `COLOR_0` to `COLOR_15`: The 16 classic colors with the xterm default values
+//! - `COLOR_16` to `COLOR_231`: A 6x6x6 color cube
+//! - `COLOR_232` to `COLOR_255`: A grayscale ramp
+//!
+//! # Example
+//!
+//! ```
+//! use polychrome::colors::xterm;
+//! use polychrome::Color;
+//!
+//! assert_eq!(xterm::COLOR_208, Color::rgb(255, 135, 0));
+//! assert_eq!(xterm::PALETTE[208], xterm::COLOR_208);
+//! ```
+use crate::Color;
+
+pub const COLOR_0: Color = Color::rgb(0, 0, 0);
+pub const COLOR_1: Color = Color::rgb(205, 0, 0);
+pub const COLOR_2: Color = Color::rgb(0, 205, 0);
+pub const COLOR_3: Color = Color::rgb(205, 205, 0);
+pub const COLOR_4: Color = Color::rgb(0, 0, 238);
+pub const COLOR_5: Color = Color::rgb(205, 0, 205);
+pub const COLOR_6: Color = Color::rgb(0, 205, 205);
+pub const COLOR_7: Color = Color::rgb(229, 229, 229);
+pub const COLOR_8: Color = Color::rgb(127, 127, 127);
+pub const COLOR_9: Color = Color::rgb(255, 0, 0);
+pub const COLOR_10: Color = Color::rgb(0, 255, 0);
+pub const COLOR_11: Color = Color::rgb(255, 255, 0);
+pub const COLOR_12: Color = Color::rgb(92, 92, 255);
+pub const COLOR_13: Color = Color::rgb(255, 0, 255);
+pub const COLOR_14: Color = Color::rgb(0, 255, 255);
+pub const COLOR_15: Color = Color::rgb(255, 255, 255);
+pub const COLOR_16: Color = Color::rgb(0, 0, 0);
+pub const COLOR_17: Color = Color::rgb(0, 0, 95);
+pub const COLOR_18: Color = Color::rgb(0, 0, 135);
+pub const COLOR_19: Color = Color::rgb(0, 0, 175);
+pub const COLOR_20: Color = Color::rgb(0, 0, 215);
+pub const COLOR_21: Color = Color::rgb(0, 0, 255);
+pub const COLOR_22: Color = Color::rgb(0, 95, 0);
+pub const COLOR_23: Color = Color::rgb(0, 95, 95);
+pub const COLOR_24: Color = Color::rgb(0, 95, 135);
+pub const COLOR_25: Color = Color::rgb(0, 95, 175);
+pub const COLOR_26: Color = Color::rgb(0, 95, 215);
+pub const COLOR_27: Color = Color::rgb(0, 95, 255);
+pub const COLOR_28: Color = Color::rgb(0, 135, 0);
+pub const COLOR_29: Color = Color::rgb(0, 135, 95);
+pub const COLOR_30: Color = Color::rgb(0, 135, 135);
+pub const COLOR_31: Color = Color::rgb(0, 135, 175);
+pub const COLOR_32: Color = Color::rgb(0, 135, 215);
+pub const COLOR_33: Color = Color::rgb(0, 135, 255);
+pub const COLOR_34: Color = Color::rgb(0, 175, 0);
+pub const COLOR_35: Color = Color::rgb(0, 175, 95);
+pub const COLOR_36: Color = Color::rgb(0, 175, 135);
+pub const COLOR_37: Color = Color::rgb(0, 175, 175);
+pub const COLOR_38: Color = Color::rgb(0, 175, 215);
+pub const COLOR_39: Color = Color::rgb(0, 175, 255);
+pub const COLOR_40: Color = Color::rgb(0, 215, 0);
+pub const COLOR_41: Color = Color::rgb(0, 215, 95);
+pub const COLOR_42: Color = Color::rgb(0, 215, 135);
+pub const COLOR_43: Color = Color::rgb(0, 215, 175);
+pub const COLOR_44: Color = Color::rgb(0, 215, 215);
+pub const COLOR_45: Color = Color::rgb(0, 215, 255);
+pub const COLOR_46: Color = Color::rgb(0, 255, 0);
+pub const COLOR_47: Color = Color::rgb(0, 255, 95);
+pub const COLOR_48: Color = Color::rgb(0, 255, 135);
+pub const COLOR_49: Color = Color::rgb(0, 255, 175);
+pub const COLOR_50: Color = Color::rgb(0, 255, 215);
+pub const COLOR_51: Color = Color::rgb(0, 255, 255);
+pub const COLOR_52: Color = Color::rgb(95, 0, 0);
+pub const COLOR_53: Color = Color::rgb(95, 0, 95);
+pub const COLOR_54: Color = Color::rgb(95, 0, 135);
+pub const COLOR_55: Color = Color::rgb(95, 0, 175);
+pub const COLOR_56: Color = Color::rgb(95, 0, 215);
+pub const COLOR_57: Color = Color::rgb(95, 0, 255);
+pub const COLOR_58: Color = Color::rgb(95, 95, 0);
+pub const COLOR_59: Color = Color::rgb(95, 95, 95);
+pub const COLOR_60: Color = Color::rgb(95, 95, 135);
+pub const COLOR_61: Color = Color::rgb(95, 95, 175);
+pub const COLOR_62: Color = Color::rgb(95, 95, 215);
+pub const COLOR_63: Color = Color::rgb(95, 95, 255);
+pub const COLOR_64: Color = Color::rgb(95, 135, 0);
+pub const COLOR_65: Color = Color::rgb(95, 135, 95);
+pub const COLOR_66: Color = Color::rgb(95, 135, 135);
+pub const COLOR_67: Color = Color::rgb(95, 135, 175);
+pub const COLOR_68: Color = Color::rgb(95, 135, 215);
+pub const COLOR_69: Color = Color::rgb(95, 135, 255);
+pub const COLOR_70: Color = Color::rgb(95, 175, 0);
+pub const COLOR_71: Color = Color::rgb(95, 175, 95);
+pub const COLOR_72: Color = Color::rgb(95, 175, 135);
+pub const COLOR_73: Color = Color::rgb(95, 175, 175);
+pub const COLOR_74: Color = Color::rgb(95, 175, 215);
+pub const COLOR_75: Color = Color::rgb(95, 175, 255);
+pub const COLOR_76: Color = Color::rgb(95, 215, 0);
+pub const COLOR_77: Color = Color::rgb(95, 215, 95);
+pub const COLOR_78: Color = Color::rgb(95, 215, 135);
+pub const COLOR_79: Color = Color::rgb(95, 215, 175);
+pub const COLOR_80: Color = Color::rgb(95, 215, 215);
+pub const COLOR_81: Color = Color::rgb(95, 215, 255);
+pub const COLOR_82: Color = Color::rgb(95, 255, 0);
+pub const COLOR_83: Color = Color::rgb(95, 255, 95);
+pub const COLOR_84: Color = Color::rgb(95, 255, 135);
+pub const COLOR_85: Color = Color::rgb(95, 255, 175);
+pub const COLOR_86: Color = Color::rgb(95, 255, 215);
+pub const COLOR_87: Color = Color::rgb(95, 255, 255);
+pub const COLOR_88: Color = Color::rgb(135, 0, 0);
+pub const COLOR_89: Color = Color::rgb(135, 0, 95);
+pub const COLOR_90: Color = Color::rgb(135, 0, 135);
+pub const COLOR_91: Color = Color::rgb(135, 0, 175);
+pub const COLOR_92: Color = Color::rgb(135, 0, 215);
+pub const COLOR_93: Color = Color::rgb(135, 0, 255);
+pub const COLOR_94: Color = Color::rgb(135, 95, 0);
+pub const COLOR_95: Color = Color::rgb(135, 95, 95);
+pub const COLOR_96: Color = Color::rgb(135, 95, 135);
+pub const COLOR_97: Color = Color::rgb(135, 95, 175);
+pub const COLOR_98: Color = Color::rgb(135, 95, 215);
+pub const COLOR_99: Color = Color::rgb(135, 95, 255);
+pub const COLOR_100: Color = Color::rgb(135, 135, 0);
+pub const COLOR_101: Color = Color::rgb(135, 135, 95);
+pub const COLOR_102: Color = Color::rgb(135, 135, 135);
+pub const COLOR_103: Color = Color::rgb(135, 135, 175);
+pub const COLOR_104: Color = Color::rgb(135, 135, 215);
+pub const COLOR_105: Color = Color::rgb(135, 135, 255);
+pub const COLOR_106: Color = Color::rgb(135, 175, 0);
+pub const COLOR_107: Color = Color::rgb(135, 175, 95);
+pub const COLOR_108: Color = Color::rgb(135, 175, 135);
+pub const COLOR_109: Color = Color::rgb(135, 175, 175);
+pub const COLOR_110: Color = Color::rgb(135, 175, 215);
+pub const COLOR_111: Color = Color::rgb(135, 175, 255);
+pub const COLOR_112: Color = Color::rgb(135, 215, 0);
+pub const COLOR_113: Color = Color::rgb(135, 215, 95);
+pub const COLOR_114: Color = Color::rgb(135, 215, 135);
+pub const COLOR_115: Color = Color::rgb(135, 215, 175);
+pub const COLOR_116: Color = Color::rgb(135, 215, 215);
+pub const COLOR_117: Color = Color::rgb(135, 215, 255);
+pub const COLOR_118: Color = Color::rgb(135, 255, 0);
+pub const COLOR_119: Color = Color::rgb(135, 255, 95);
+pub const COLOR_120: Color = Color::rgb(135, 255, 135);
+pub const COLOR_121: Color = Color::rgb(135, 255, 175);
+pub const COLOR_122: Color = Color::rgb(135, 255, 215);
+pub const COLOR_123: Color = Color::rgb(135, 255, 255);
+pub const COLOR_124: Color = Color::rgb(175, 0, 0);
+pub const COLOR_125: Color = Color::rgb(175, 0, 95);
+pub const COLOR_126: Color = Color::rgb(175, 0, 135);
+pub const COLOR_127: Color = Color::rgb(175, 0, 175);
+pub const COLOR_128: Color = Color::rgb(175, 0, 215);
+pub const COLOR_129: Color = Color::rgb(175, 0, 255);
+pub const COLOR_130: Color = Color::rgb(175, 95, 0);
+pub const COLOR_131: Color = Color::rgb(175, 95, 95);
+pub const COLOR_132: Color = Color::rgb(175, 95, 135);
+pub const COLOR_133: Color = Color::rgb(175, 95, 175);
+pub const COLOR_134: Color = Color::rgb(175, 95, 215);
+pub const COLOR_135: Color = Color::rgb(175, 95, 255);
+pub const COLOR_136: Color = Color::rgb(175, 135, 0);
+pub const COLOR_137: Color = Color::rgb(175, 135, 95);
+pub const COLOR_138: Color = Color::rgb(175, 135, 135);
+pub const COLOR_139: Color = Color::rgb(175, 135, 175);
+pub const COLOR_140: Color = Color::rgb(175, 135, 215);
+pub const COLOR_141: Color = Color::rgb(175, 135, 255);
+pub const COLOR_142: Color = Color::rgb(175, 175, 0);
+pub const COLOR_143: Color = Color::rgb(175, 175, 95);
+pub const COLOR_144: Color = Color::rgb(175, 175, 135);
+pub const COLOR_145: Color = Color::rgb(175, 175, 175);
+pub const COLOR_146: Color = Color::rgb(175, 175, 215);
+pub const COLOR_147: Color = Color::rgb(175, 175, 255);
+pub const COLOR_148: Color = Color::rgb(175, 215, 0);
+pub const COLOR_149: Color = Color::rgb(175, 215, 95);
+pub const COLOR_150: Color = Color::rgb(175, 215, 135);
+pub const COLOR_151: Color = Color::rgb(175, 215, 175);
+pub const COLOR_152: Color = Color::rgb(175, 215, 215);
+pub const COLOR_153: Color = Color::rgb(175, 215, 255);
+pub const COLOR_154: Color = Color::rgb(175, 255, 0);
+pub const COLOR_155: Color = Color::rgb(175, 255, 95);
+pub const COLOR_156: Color = Color::rgb(175, 255, 135);
+pub const COLOR_157: Color = Color::rgb(175, 255, 175);
+pub const COLOR_158: Color = Color::rgb(175, 255, 215);
+pub const COLOR_159: Color = Color::rgb(175, 255, 255);
+pub const COLOR_160: Color = Color::rgb(215, 0, 0);
+pub const COLOR_161: Color = Color::rgb(215, 0, 95);
+pub const COLOR_162: Color = Color::rgb(215, 0, 135);
+pub const COLOR_163: Color = Color::rgb(215, 0, 175);
+pub const COLOR_164: Color = Color::rgb(215, 0, 215);
+pub const COLOR_165: Color = Color::rgb(215, 0, 255);
+pub const COLOR_166: Color = Color::rgb(215, 95, 0);
+pub const COLOR_167: Color = Color::rgb(215, 95, 95);
+pub const COLOR_168: Color = Color::rgb(215, 95, 135);
+pub const COLOR_169: Color = Color::rgb(215, 95, 175);
+pub const COLOR_170: Color = Color::rgb(215, 95, 215);
+pub const COLOR_171: Color = Color::rgb(215, 95, 255);
+pub const COLOR_172: Color = Color::rgb(215, 135, 0);
+pub const COLOR_173: Color = Color::rgb(215, 135, 95);
+pub const COLOR_174: Color = Color::rgb(215, 135, 135);
+pub const COLOR_175: Color = Color::rgb(215, 135, 175);
+pub const COLOR_176: Color = Color::rgb(215, 135, 215);
+pub const COLOR_177: Color = Color::rgb(215, 135, 255);
+pub const COLOR_178: Color = Color::rgb(215, 175, 0);
+pub const COLOR_179: Color = Color::rgb(215, 175, 95);
+pub const COLOR_180: Color = Color::rgb(215, 175, 135);
+pub const COLOR_181: Color = Color::rgb(215, 175, 175);
+pub const COLOR_182: Color = Color::rgb(215, 175, 215);
+pub const COLOR_183: Color = Color::rgb(215, 175, 255);
+pub const COLOR_184: Color = Color::rgb(215, 215, 0);
+pub const COLOR_185: Color = Color::rgb(215, 215, 95);
+pub const COLOR_186: Color = Color::rgb(215, 215, 135);
+pub const COLOR_187: Color = Color::rgb(215, 215, 175);
+pub const COLOR_188: Color = Color::rgb(215, 215, 215);
+pub const COLOR_189: Color = Color::rgb(215, 215, 255);
+pub const COLOR_190: Color = Color::rgb(215, 255, 0);
+pub const COLOR_191: Color = Color::rgb(215, 255, 95);
+pub const COLOR_192: Color = Color::rgb(215, 255, 135);
+pub const COLOR_193: Color = Color::rgb(215, 255, 175);
+pub const COLOR_194: Color = Color::rgb(215, 255, 215);
+pub const COLOR_195: Color = Color::rgb(215, 255, 255);
+pub const COLOR_196: Color = Color::rgb(255, 0, 0);
+pub const COLOR_197: Color = Color::rgb(255, 0, 95);
+pub const COLOR_198: Color = Color::rgb(255, 0, 135);
+pub const COLOR_199: Color = Color::rgb(255, 0, 175);
+pub const COLOR_200: Color = Color::rgb(255, 0, 215);
+pub const COLOR_201: Color = Color::rgb(255, 0, 255);
+pub const COLOR_202: Color = Color::rgb(255, 95, 0);
+pub const COLOR_203: Color = Color::rgb(255, 95, 95);
+pub const COLOR_204: Color = Color::rgb(255, 95, 135);
+pub const COLOR_205: Color = Color::rgb(255, 95, 175);
+pub const COLOR_206: Color = Color::rgb(255, 95, 215);
+pub const COLOR_207: Color = Color::rgb(255, 95, 255);
+pub const COLOR_208: Color = Color::rgb(255, 135, 0);
+pub const COLOR_209: Color = Color::rgb(255, 135, 95);
+pub const COLOR_210: Color = Color::rgb(255, 135, 135);
+pub const COLOR_211: Color = Color::rgb(255, 135, 175);
+pub const COLOR_212: Color = Color::rgb(255, 135, 215);
+pub const COLOR_213: Color = Color::rgb(255, 135, 255);
+pub const COLOR_214: Color = Color::rgb(255, 175, 0);
+pub const COLOR_215: Color = Color::rgb(255, 175, 95);
+pub const COLOR_216: Color = Color::rgb(255, 175, 135);
+pub const COLOR_217: Color = Color::rgb(255, 175, 175);
+pub const COLOR_218: Color = Color::rgb(255, 175, 215);
+pub const COLOR_219: Color = Color::rgb(255, 175, 255);
+pub const COLOR_220: Color = Color::rgb(255, 215, 0);
+pub const COLOR_221: Color = Color::rgb(255, 215, 95);
+pub const COLOR_222: Color = Color::rgb(255, 215, 135);
+pub const COLOR_223: Color = Color::rgb(255, 215, 175);
+pub const COLOR_224: Color = Color::rgb(255, 215, 215);
+pub const COLOR_225: Color = Color::rgb(255, 215, 255);
+pub const COLOR_226: Color = Color::rgb(255, 255, 0);
+pub const COLOR_227: Color = Color::rgb(255, 255, 95);
+pub const COLOR_228: Color = Color::rgb(255, 255, 135);
+pub const COLOR_229: Color = Color::rgb(255, 255, 175);
+pub const COLOR_230: Color = Color::rgb(255, 255, 215);
+pub const COLOR_231: Color = Color::rgb(255, 255, 255);
+pub const COLOR_232: Color = Color::rgb(8, 8, 8);
+pub const COLOR_233: Color = Color::rgb(18, 18, 18);
+pub const COLOR_234: Color = Color::rgb(28, 28, 28);
+pub const COLOR_235: Color = Color::rgb(38, 38, 38);
+pub const COLOR_236: Color = Color::rgb(48, 48, 48);
+pub const COLOR_237: Color = Color::rgb(58, 58, 58);
+pub const COLOR_238: Color = Color::rgb(68, 68, 68);
+pub const COLOR_239: Color = Color::rgb(78, 78, 78);
+pub const COLOR_240: Color = Color::rgb(88, 88, 88);
+pub const COLOR_241: Color = Color::rgb(98, 98, 98);
+pub const COLOR_242: Color = Color::rgb(108, 108, 108);
+pub const COLOR_243: Color = Color::rgb(118, 118, 118);
+pub const COLOR_244: Color = Color::rgb(128, 128, 128);
+pub const COLOR_245: Color = Color::rgb(138, 138, 138);
+pub const COLOR_246: Color = Color::rgb(148, 148, 148);
+pub const COLOR_247: Color = Color::rgb(158, 158, 158);
+pub const COLOR_248: Color = Color::rgb(168, 168, 168);
+pub const COLOR_249: Color = Color::rgb(178, 178, 178);
+pub const COLOR_250: Color = Color::rgb(188, 188, 188);
+pub const COLOR_251: Color = Color::rgb(198, 198, 198);
+pub const COLOR_252: Color = Color::rgb(208, 208, 208);
+pub const COLOR_253: Color = Color::rgb(218, 218, 218);
+pub const COLOR_254: Color = Color::rgb(228, 228, 228);
+pub const COLOR_255: Color = Color::rgb(238, 238, 238);
+
+/// Every color of the palette, indexed by its xterm number
+pub const PALETTE: [Color; 256] = [
+    COLOR_0,
+    COLOR_1,
+    COLOR_2,
+    COLOR_3,
+    COLOR_4,
+    COLOR_5,
+    COLOR_6,
+    COLOR_7,
+    COLOR_8,
+    COLOR_9,
+    COLOR_10,
+    COLOR_11,
+    COLOR_12,
+    COLOR_13,
+    COLOR_14,
+    COLOR_15,
+    COLOR_16,
+    COLOR_17,
+    COLOR_18,
+    COLOR_19,
+    COLOR_20,
+    COLOR_21,
+    COLOR_22,
+    COLOR_23,
+    COLOR_24,
+    COLOR_25,
+    COLOR_26,
+    COLOR_27,
+    COLOR_28,
+    COLOR_29,
+    COLOR_30,
+    COLOR_31,
+    COLOR_32,
+    COLOR_33,
+    COLOR_34,
+    COLOR_35,
+    COLOR_36,
+    COLOR_37,
+    COLOR_38,
+    COLOR_39,
+    COLOR_40,
+    COLOR_41,
+    COLOR_42,
+    COLOR_43,
+    COLOR_44,
+    COLOR_45,
+    COLOR_46,
+    COLOR_47,
+    COLOR_48,
+    COLOR_49,
+    COLOR_50,
+    COLOR_51,
+    COLOR_52,
+    COLOR_53,
+    COLOR_54,
+    COLOR_55,
+    COLOR_56,
+    COLOR_57,
+    COLOR_58,
+    COLOR_59,
+    COLOR_60,
+    COLOR_61,
+    COLOR_62,
+    COLOR_63,
+    COLOR_64,
+    COLOR_65,
+    COLOR_66,
+    COLOR_67,
+    COLOR_68,
+    COLOR_69,
+    COLOR_70,
+    COLOR_71,
+    COLOR_72,
+    COLOR_73,
+    COLOR_74,
+    COLOR_75,
+    COLOR_76,
+    COLOR_77,
+    COLOR_78,
+    COLOR_79,
+    COLOR_80,
+    COLOR_81,
+    COLOR_82,
+    COLOR_83,
+    COLOR_84,
+    COLOR_85,
+    COLOR_86,
+    COLOR_87,
+    COLOR_88,
+    COLOR_89,
+    COLOR_90,
+    COLOR_91,
+    COLOR_92,
+    COLOR_93,
+    COLOR_94,
+    COLOR_95,
+    COLOR_96,
+    COLOR_97,
+    COLOR_98,
+    COLOR_99,
+    COLOR_100,
+    COLOR_101,
+    COLOR_102,
+    COLOR_103,
+    COLOR_104,
+    COLOR_105,
+    COLOR_106,
+    COLOR_107,
+    COLOR_108,
+    COLOR_109,
+    COLOR_110,
+    COLOR_111,
+    COLOR_112,
+    COLOR_113,
+    COLOR_114,
+    COLOR_115,
+    COLOR_116,
+    COLOR_117,
+    COLOR_118,
+    COLOR_119,
+    COLOR_120,
+    COLOR_121,
+    COLOR_122,
+    COLOR_123,
+    COLOR_124,
+    COLOR_125,
+    COLOR_126,
+    COLOR_127,
+    COLOR_128,
+    COLOR_129,
+    COLOR_130,
+    COLOR_131,
+    COLOR_132,
+    COLOR_133,
+    COLOR_134,
+    COLOR_135,
+    COLOR_136,
+    COLOR_137,
+    COLOR_138,
+    COLOR_139,
+    COLOR_140,
+    COLOR_141,
+    COLOR_142,
+    COLOR_143,
+    COLOR_144,
+    COLOR_145,
+    COLOR_146,
+    COLOR_147,
+    COLOR_148,
+    COLOR_149,
+    COLOR_150,
+    COLOR_151,
+    COLOR_152,
+    COLOR_153,
+    COLOR_154,
+    COLOR_155,
+    COLOR_156,
+    COLOR_157,
+    COLOR_158,
+    COLOR_159,
+    COLOR_160,
+    COLOR_161,
+    COLOR_162,
+    COLOR_163,
+    COLOR_164,
+    COLOR_165,
+    COLOR_166,
+    COLOR_167,
+    COLOR_168,
+    COLOR_169,
+    COLOR_170,
+    COLOR_171,
+    COLOR_172,
+    COLOR_173,
+    COLOR_174,
+    COLOR_175,
+    COLOR_176,
+    COLOR_177,
+    COLOR_178,
+    COLOR_179,
+    COLOR_180,
+    COLOR_181,
+    COLOR_182,
+    COLOR_183,
+    COLOR_184,
+    COLOR_185,
+    COLOR_186,
+    COLOR_187,
+    COLOR_188,
+    COLOR_189,
+    COLOR_190,
+    COLOR_191,
+    COLOR_192,
+    COLOR_193,
+    COLOR_194,
+    COLOR_195,
+    COLOR_196,
+    COLOR_197,
+    COLOR_198,
+    COLOR_199,
+    COLOR_200,
+    COLOR_201,
+    COLOR_202,
+    COLOR_203,
+    COLOR_204,
+    COLOR_205,
+    COLOR_206,
+    COLOR_207,
+    COLOR_208,
+    COLOR_209,
+    COLOR_210,
+    COLOR_211,
+    COLOR_212,
+    COLOR_213,
+    COLOR_214,
+    COLOR_215,
+    COLOR_216,
+    COLOR_217,
+    COLOR_218,
+    COLOR_219,
+    COLOR_220,
+    COLOR_221,
+    COLOR_222,
+    COLOR_223,
+    COLOR_224,
+    COLOR_225,
+    COLOR_226,
+    COLOR_227,
+    COLOR_228,
+    COLOR_229,
+    COLOR_230,
+    COLOR_231,
+    COLOR_232,
+    COLOR_233,
+    COLOR_234,
+    COLOR_235,
+    COLOR_236,
+    COLOR_237,
+    COLOR_238,
+    COLOR_239,
+    COLOR_240,
+    COLOR_241,
+    COLOR_242,
+    COLOR_243,
+    COLOR_244,
+    COLOR_245,
+    COLOR_246,
+    COLOR_247,
+    COLOR_248,
+    COLOR_249,
+    COLOR_250,
+    COLOR_251,
+    COLOR_252,
+    COLOR_253,
+    COLOR_254,
+    COLOR_255,
+];
@@ -0,0 +1,605 @@
+// effects.rs
+use crate::utils::{escape_len, graphemes};
+use crate::{Color, Style};
+#[cfg(feature = "ansi")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::io::Write;
+use std::time::Duration;
+
+/// Print the styled text to stdout one character at a time, waiting `delay` between them
+///
+/// Blocks until the whole text is printed, see [`Typewriter`] to print the characters
+/// from your own loop instead.
+///
+/// # Params
+/// - `text`: The text to print, it can already contain escape codes
+/// - `style`: The style of the text
+/// - `delay`: The time between two characters
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{typewriter, Style};
+/// use std::time::Duration;
+///
+/// typewriter("Welcome to polychrome!\n", &Style::new().color(0, 255, 0).bold(), Duration::from_millis(1)).unwrap();
+/// ```
+pub fn typewriter(text: &str, style: &Style, delay: Duration) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    for (i, chunk) in Typewriter::new(text, style).enumerate() {
+        if i > 0 {
+            std::thread::sleep(delay);
+        }
+        stdout.write_all(chunk.as_bytes())?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
+
+/// An iterator over the styled text split into one chunk per visible character
+///
+/// Every chunk holds a character with the escape codes around it, so printing the chunks
+/// in order gives exactly the styled text: escape sequences are never cut and gradients keep
+/// the color of every character.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Style, Typewriter};
+///
+/// let style = Style::new().gradient((255, 0, 0), (0, 0, 255));
+/// let chunks: Vec<String> = Typewriter::new("Hello", &style).collect();
+/// assert_eq!(chunks.len(), 5);
+/// assert_eq!(chunks.concat(), style.apply("Hello").to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Typewriter {
+    rendered: String,
+    position: usize,
+}
+
+impl Typewriter {
+    /// Split the text styled with `style` into chunks
+    ///
+    /// # Params
+    /// - `text`: The text to reveal, it can already contain escape codes
+    /// - `style`: The style of the text
+    pub fn new(text: &str, style: &Style) -> Typewriter {
+        Typewriter { rendered: style.apply(text).to_string(), position: 0 }
+    }
+}
+
+impl Iterator for Typewriter {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let rest = &self.rendered[self.position..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        // The escapes before the character, the character, then the escapes up to the next one
+        let mut end = 0;
+        let mut revealed = false;
+        while let Some(tail) = rest.get(end..).filter(|tail| !tail.is_empty()) {
+            if tail.starts_with('\x1b') {
+                end += escape_len(tail);
+            } else if revealed {
+                break;
+            } else {
+                end += graphemes(tail).next().map_or(tail.len(), str::len);
+                revealed = true;
+            }
+        }
+        if !revealed {
+            // Only escape codes are left, there is nothing more to reveal
+            self.position = self.rendered.len();
+            return None;
+        }
+        self.position += end;
+        Some(rest[..end].to_string())
+    }
+}
+
+/// A styled text scrolling from right to left inside a fixed width, for status lines narrower
+/// than their content
+///
+/// The text scrolls by one character per frame, followed by a few spaces before it comes back.
+/// A text that already fits in the width is printed as is, padded with spaces.
+///
+/// # Example
+///
+/// ```no_run
+/// use polychrome::{Marquee, Style};
+/// use std::io::Write;
+///
+/// let marquee = Marquee::new("Downloading polychrome-2.0.3.crate from crates.io", &Style::new().bold(), 20);
+/// for frame in marquee.frames().cycle().take(200) {
+///     print!("\r[{}]", frame);
+///     std::io::stdout().flush().unwrap();
+///     std::thread::sleep(std::time::Duration::from_millis(100));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Marquee {
+    rendered: String,
+    cells: Vec<Cell>,
+    width: usize,
+    gap: usize,
+}
+
+/// A visible character of a rendered text
+#[derive(Debug, Clone)]
+struct Cell {
+    /// The escape codes right before the character
+    escapes: String,
+    /// The escape codes in effect for the character, since the last reset
+    state: String,
+    text: String,
+    width: usize,
+}
+
+impl Marquee {
+    /// Create a marquee of `width` columns with a gap of 3 spaces
+    ///
+    /// # Params
+    /// - `text`: The text to scroll, it can already contain escape codes
+    /// - `style`: The style of the text
+    /// - `width`: The number of columns of every frame
+    pub fn new(text: &str, style: &Style, width: usize) -> Marquee {
+        let rendered = style.apply(text).to_string();
+        let mut cells = Vec::new();
+        let (mut escapes, mut state) = (String::new(), String::new());
+        let mut rest = rendered.as_str();
+        while let Some(grapheme) = graphemes(rest).next() {
+            if grapheme.starts_with('\x1b') {
+                let escape = &rest[..escape_len(rest)];
+                escapes.push_str(escape);
+                if escape == "\x1b[0m" || escape == "\x1b[m" {
+                    state.clear();
+                } else {
+                    state.push_str(escape);
+                }
+                rest = &rest[escape.len()..];
+                continue;
+            }
+            let (text, width) = (grapheme.to_string(), crate::utils::display_width(grapheme));
+            cells.push(Cell { escapes: std::mem::take(&mut escapes), state: state.clone(), text, width });
+            rest = &rest[grapheme.len()..];
+        }
+        Marquee { rendered, cells, width, gap: 3 }
+    }
+
+    /// Set the number of spaces between the end of the text and its next start
+    pub fn gap(mut self, gap: usize) -> Marquee {
+        self.gap = gap;
+        self
+    }
+
+    /// Return the number of frames before the animation loops
+    pub fn len(&self) -> usize {
+        if self.fits() { 1 } else { self.cells.len() + self.gap.max(1) }
+    }
+
+    /// Return true if the text is empty
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Return frame number `index`, the text shifted by `index` characters
+    ///
+    /// Every frame takes exactly `width` columns, wide characters that don't fit
+    /// at the edge are replaced by spaces.
+    pub fn frame(&self, index: usize) -> String {
+        let content_width: usize = self.cells.iter().map(|cell| cell.width).sum();
+        if self.fits() {
+            return format!("{}{}", self.rendered, " ".repeat(self.width - content_width));
+        }
+
+        let period = self.len();
+        let mut frame = String::new();
+        let (mut used, mut styled) = (0, false);
+        let mut previous = None;
+        for i in (index..).map(|i| i % period) {
+            let cell = self.cells.get(i);
+            let width = cell.map_or(1, |cell| cell.width);
+            if used + width > self.width {
+                break;
+            }
+            used += width;
+
+            let Some(cell) = cell else {
+                if styled {
+                    frame.push_str("\x1b[0m");
+                    styled = false;
+                }
+                frame.push(' ');
+                previous = None;
+                continue;
+            };
+            if i > 0 && previous == Some(i - 1) {
+                frame.push_str(&cell.escapes);
+            } else {
+                if styled {
+                    frame.push_str("\x1b[0m");
+                }
+                frame.push_str(&cell.state);
+            }
+            styled = !cell.state.is_empty();
+            frame.push_str(&cell.text);
+            previous = Some(i);
+        }
+        if styled {
+            frame.push_str("\x1b[0m");
+        }
+        frame.push_str(&" ".repeat(self.width - used));
+        frame
+    }
+
+    /// Return the frames of one loop of the animation, call `cycle()` on it to loop forever
+    pub fn frames(&self) -> impl Iterator<Item = String> + Clone + '_ {
+        (0..self.len()).map(|index| self.frame(index))
+    }
+
+    fn fits(&self) -> bool {
+        self.cells.iter().map(|cell| cell.width).sum::<usize>() <= self.width
+    }
+}
+
+/// A styled text whose lightness slowly fades out and back in, for "waiting" indicators
+///
+/// Unlike the blink text style it works in every terminal and never hides the text: the
+/// foreground colors, gradients included, are dimmed by up to `depth` following a smooth wave.
+/// A style without a foreground color pulses from white.
+///
+/// # Example
+///
+/// ```no_run
+/// use polychrome::{Pulse, Style};
+/// use std::io::Write;
+///
+/// let pulse = Pulse::new("Waiting for the server...", &Style::new().color(0, 200, 255), 30);
+/// for frame in pulse.frames().cycle().take(120) {
+///     print!("\r{}", frame);
+///     std::io::stdout().flush().unwrap();
+///     std::thread::sleep(std::time::Duration::from_millis(50));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pulse {
+    text: String,
+    style: Style,
+    period: usize,
+    depth: f32,
+}
+
+impl Pulse {
+    /// Create a pulse lasting `period` frames, dimming the text by half at its darkest
+    ///
+    /// # Params
+    /// - `text`: The text to print
+    /// - `style`: The style of the text at full lightness
+    /// - `period`: The number of frames of a whole fade out and in
+    pub fn new(text: &str, style: &Style, period: usize) -> Pulse {
+        Pulse { text: text.to_string(), style: style.clone(), period: period.max(1), depth: 0.5 }
+    }
+
+    /// Set how much the lightness goes down at the darkest frame
+    ///
+    /// # Params
+    /// - `depth`: From `0.0` (no change) to `1.0` (black), clamped
+    pub fn depth(mut self, depth: f32) -> Pulse {
+        self.depth = depth.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Return the number of frames before the animation loops
+    pub fn len(&self) -> usize {
+        self.period
+    }
+
+    /// Return true if the text is empty
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Return frame number `index`, at full lightness on the first frame of every period
+    pub fn frame(&self, index: usize) -> String {
+        let phase = (index % self.period) as f32 / self.period as f32;
+        let amount = self.depth * (1.0 - (phase * std::f32::consts::TAU).cos()) / 2.0;
+        let dim = |color: Color| {
+            let (h, s, l) = color.to_hsl();
+            Color::from_hsl(h, s, l * (1.0 - amount))
+        };
+
+        let mut style = self.style.clone();
+        style.fg = Some(dim(style.fg.map_or(Color::WHITE, |color| color.to_rgb())).into());
+        style.gradient = style.gradient.map(|gradient| gradient.map(dim));
+        style.apply(self.text.as_str()).to_string()
+    }
+
+    /// Return the frames of one loop of the animation, call `cycle()` on it to loop forever
+    pub fn frames(&self) -> impl Iterator<Item = String> + Clone + '_ {
+        (0..self.len()).map(|index| self.frame(index))
+    }
+}
+
+/// The rainbow of `lolcat`, with the same parameters and colors
+///
+/// Every character at column `x` of line `y` gets the color of the sine waves of the three
+/// channels at `frequency * (seed + y + x / spread)`, so the rainbow runs diagonally through
+/// the lines.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::Lolcat;
+///
+/// let lolcat = Lolcat::new().frequency(0.2).spread(4.0).seed(42);
+/// println!("{}", lolcat.paint("Hello, world!\nHello again!"));
+/// ```
+#[cfg(feature = "ansi")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lolcat {
+    frequency: f64,
+    spread: f64,
+    seed: u64,
+}
+
+#[cfg(feature = "ansi")]
+impl Lolcat {
+    /// Create the rainbow with the defaults of `lolcat`: a frequency of 0.1, a spread of 3.0 and a random seed
+    pub fn new() -> Lolcat {
+        Lolcat { frequency: 0.1, spread: 3.0, seed: rand::thread_rng().gen_range(0..256) }
+    }
+
+    /// Set how fast the colors change, `lolcat -F`
+    pub fn frequency(mut self, frequency: f64) -> Lolcat {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Set the number of characters taking about the same color, `lolcat -p`
+    pub fn spread(mut self, spread: f64) -> Lolcat {
+        self.spread = spread.max(f64::EPSILON);
+        self
+    }
+
+    /// Set the starting point of the rainbow, `lolcat -S`, the same seed always gives the same colors
+    pub fn seed(mut self, seed: u64) -> Lolcat {
+        self.seed = seed;
+        self
+    }
+
+    /// Paint the text, escape codes already in it are removed first like `lolcat` does
+    pub fn paint(&self, text: &str) -> String {
+        self.frame(text, 0)
+    }
+
+    /// Paint frame number `index` of the rainbow moving through the text
+    ///
+    /// Every frame shifts the colors as much as one line does, so printing the frames one after
+    /// the other over the same lines animates the text like `lolcat -a`.
+    pub fn frame(&self, text: &str, index: usize) -> String {
+        let plain = crate::utils::strip_ansi(text);
+        let mut positions = crate::gradient::CharPositions::default();
+        let colors: Vec<Color> = graphemes(&plain)
+            .map(|grapheme| {
+                let position = positions.position_of(grapheme);
+                let offset = self.seed.wrapping_add(position.line as u64).wrapping_add(index as u64) as f64;
+                self.color_at(offset + position.column as f64 / self.spread)
+            })
+            .collect();
+        crate::color::paint_chars(&plain, |i| colors[i])
+    }
+
+    fn color_at(&self, x: f64) -> Color {
+        let channel = |phase: f64| ((self.frequency * x + phase).sin() * 127.0 + 128.0) as u8;
+        let third = 2.0 * std::f64::consts::PI / 3.0;
+        Color::rgb(channel(0.0), channel(third), channel(2.0 * third))
+    }
+}
+
+#[cfg(feature = "ansi")]
+impl Default for Lolcat {
+    fn default() -> Lolcat {
+        Lolcat::new()
+    }
+}
+
+/// The characters replacing the glitched ones
+#[cfg(feature = "ansi")]
+const GLITCH_CHARS: [char; 15] = ['#', '%', '&', '@', '$', '*', '!', '?', '/', '\\', '|', '░', '▒', '▓', '█'];
+
+/// A styled text with randomly shifted colors and replaced characters, changing every frame
+///
+/// The colors come from the style (its gradient, its color or white) and every channel of every
+/// character is moved by up to `amount`. The same seed always gives the same frames.
+///
+/// # Example
+///
+/// ```no_run
+/// use polychrome::{Glitch, Style};
+/// use std::io::Write;
+///
+/// let glitch = Glitch::new("SYSTEM BOOT", &Style::new().color(0, 255, 128).bold()).substitution(0.1);
+/// for frame in 0..30 {
+///     print!("\r{}", glitch.frame(frame));
+///     std::io::stdout().flush().unwrap();
+///     std::thread::sleep(std::time::Duration::from_millis(60));
+/// }
+/// println!("\r{}", Style::new().color(0, 255, 128).bold().apply("SYSTEM BOOT"));
+/// ```
+#[cfg(feature = "ansi")]
+#[derive(Debug, Clone)]
+pub struct Glitch {
+    text: String,
+    style: Style,
+    amount: u8,
+    substitution: f32,
+    seed: u64,
+}
+
+#[cfg(feature = "ansi")]
+impl Glitch {
+    /// Create a glitch with a color noise of 60, no replaced characters and a random seed
+    ///
+    /// # Params
+    /// - `text`: The text to glitch
+    /// - `style`: The style of the text, its colors are the ones shifted
+    pub fn new(text: &str, style: &Style) -> Glitch {
+        let seed = rand::thread_rng().gen();
+        Glitch { text: text.to_string(), style: style.clone(), amount: 60, substitution: 0.0, seed }
+    }
+
+    /// Set the maximum change of every color channel, `0` keeps the colors of the style
+    pub fn amount(mut self, amount: u8) -> Glitch {
+        self.amount = amount;
+        self
+    }
+
+    /// Set the probability of every character but spaces to be replaced by a symbol in a frame
+    ///
+    /// # Params
+    /// - `probability`: From `0.0` (never) to `1.0` (always), clamped
+    pub fn substitution(mut self, probability: f32) -> Glitch {
+        self.substitution = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the seed of the random noise
+    pub fn seed(mut self, seed: u64) -> Glitch {
+        self.seed = seed;
+        self
+    }
+
+    /// Return frame number `index`, every frame has its own noise
+    pub fn frame(&self, index: usize) -> String {
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(index as u64));
+        let base = self.style.fg.map_or(Color::WHITE, |color| color.to_rgb());
+        let gradient = self.style.gradient.as_ref().filter(|gradient| !gradient.is_empty());
+        let last = graphemes(&self.text).count().saturating_sub(1);
+        let amount = self.amount as i16;
+
+        let mut colors = Vec::with_capacity(last + 1);
+        let mut glitched = String::with_capacity(self.text.len());
+        for (i, grapheme) in graphemes(&self.text).enumerate() {
+            let t = if last == 0 { 0.0 } else { i as f32 / last as f32 };
+            let Color { r, g, b } = gradient.map_or(base, |gradient| gradient.at(t));
+            let mut noise = |channel: u8| (channel as i16 + rng.gen_range(-amount..=amount)).clamp(0, 255) as u8;
+            colors.push(Color::rgb(noise(r), noise(g), noise(b)));
+
+            if !grapheme.chars().all(char::is_whitespace) && rng.gen::<f32>() < self.substitution {
+                glitched.push(GLITCH_CHARS[rng.gen_range(0..GLITCH_CHARS.len())]);
+            } else {
+                glitched.push_str(grapheme);
+            }
+        }
+
+        let painted = crate::color::paint_chars(&glitched, |i| colors[i]);
+        let attributes = Style { fg: None, gradient: None, ..self.style.clone() };
+        if attributes == Style::new() {
+            return painted;
+        }
+        attributes.apply(painted).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typewriter() {
+        let style = Style::new().color(255, 0, 0).bold().mode(crate::ColorMode::TrueColor);
+        let text = "a\x1b[4mb\x1b[0mc";
+        let chunks: Vec<String> = Typewriter::new(text, &style).collect();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), style.apply(text).to_string());
+        assert!(chunks.iter().all(|chunk| crate::utils::strip_ansi(chunk).chars().count() == 1));
+
+        let gradient = Style::new().gradient((255, 0, 0), (0, 0, 255)).mode(crate::ColorMode::TrueColor);
+        assert_eq!(Typewriter::new("Hello", &gradient).collect::<String>(), gradient.apply("Hello").to_string());
+        assert_eq!(Typewriter::new("", &style).next(), None);
+        assert_eq!(Typewriter::new("\x1b[1m\x1b[0m", &style).next(), None);
+        assert_eq!(Typewriter::new("", &Style::new()).next(), None);
+    }
+
+    #[test]
+    fn test_marquee() {
+        let style = Style::new().color(255, 0, 0).mode(crate::ColorMode::TrueColor);
+        let marquee = Marquee::new("abcdef", &style, 4).gap(2);
+        let plain = |index| crate::utils::strip_ansi(&marquee.frame(index));
+        assert_eq!(marquee.len(), 8);
+        assert_eq!([plain(0), plain(3), plain(5), plain(8)], ["abcd", "def ", "f  a", "abcd"]);
+        assert_eq!(marquee.frames().count(), 8);
+
+        let short = Marquee::new("ab", &style, 4);
+        assert_eq!(crate::utils::strip_ansi(&short.frame(3)), "ab  ");
+        assert_eq!(short.len(), 1);
+        assert_eq!(crate::utils::strip_ansi(&Marquee::new("日本語", &style, 3).frame(0)), "日 ");
+
+        #[cfg(feature = "ansi")]
+        assert_eq!(marquee.frame(5), "\x1b[38;2;255;0;0mf\x1b[0m  \x1b[38;2;255;0;0ma\x1b[0m");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_pulse() {
+        let style = Style::new().color(200, 0, 0).bold().mode(crate::ColorMode::TrueColor);
+        let pulse = Pulse::new("wait", &style, 4);
+        assert_eq!(pulse.frame(0), style.apply("wait").to_string());
+        assert_eq!(pulse.frame(2), "\x1b[38;2;100;0;0;1mwait\x1b[0m");
+        assert_eq!(pulse.frame(4), pulse.frame(0));
+        assert_eq!(pulse.frames().count(), 4);
+        assert_eq!(pulse.clone().depth(0.0).frame(2), pulse.frame(0));
+
+        let gradient = Style::new().gradient((200, 0, 0), (0, 0, 200)).mode(crate::ColorMode::TrueColor);
+        let dimmed = Pulse::new("ab", &gradient, 2).depth(1.0).frame(1);
+        assert_eq!(dimmed, "\x1b[38;2;0;0;0mab\x1b[0m");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_lolcat() {
+        let _mode = crate::ColorMode::global_guard(crate::ColorMode::TrueColor);
+        let lolcat = Lolcat::new().seed(0);
+        assert!(lolcat.paint("ab").starts_with("\x1b[38;2;128;237;18ma"));
+        assert_eq!(lolcat.paint("\x1b[1mab\x1b[0m"), lolcat.paint("ab"));
+        assert_eq!(lolcat.frame("a\nb", 1), Lolcat::new().seed(1).paint("a\nb"));
+
+        // The second line starts with the color of the first line shifted by one
+        let text = "a\nb";
+        let painted = lolcat.paint(text);
+        assert!(painted.ends_with(&format!("b{}", crate::utils::reset_code())));
+        assert!(painted.contains(&lolcat.seed(1).paint("b").replace("\x1b[0m", "")));
+        assert_eq!(Lolcat::new().seed(7).paint(text), Lolcat::new().seed(7).paint(text));
+
+        // The offset wraps around instead of overflowing
+        let last = Lolcat::new().seed(u64::MAX);
+        assert_eq!(crate::utils::strip_ansi(&last.paint(text)), text);
+        assert_eq!(last.frame("ab", 1), Lolcat::new().seed(0).paint("ab"));
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_glitch() {
+        let _mode = crate::ColorMode::global_guard(crate::ColorMode::TrueColor);
+        let style = Style::new().color(0, 255, 128);
+        let calm = Glitch::new("glitch me", &style).amount(0).seed(1);
+        assert_eq!(calm.frame(0), "\x1b[38;2;0;255;128mglitch me\x1b[0m");
+
+        let glitch = Glitch::new("glitch me", &style).seed(1).substitution(1.0);
+        assert_eq!(glitch.frame(3), glitch.clone().frame(3));
+        assert_ne!(glitch.frame(3), glitch.frame(4));
+        let plain = crate::utils::strip_ansi(&glitch.frame(3));
+        assert_eq!(plain.chars().count(), 9);
+        assert_eq!(plain.chars().nth(6), Some(' '));
+        assert!(plain.chars().filter(|c| *c != ' ').all(|c| GLITCH_CHARS.contains(&c)));
+
+        let bold = style.clone().bold().mode(crate::ColorMode::TrueColor);
+        let bold = Glitch::new("ab", &bold).amount(0).seed(1).frame(0);
+        assert_eq!(crate::utils::strip_ansi(&bold), "ab");
+        assert!(bold.contains("\x1b[1m"));
+    }
+}
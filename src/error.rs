@@ -0,0 +1,80 @@
+// error.rs
+
+/// The error returned when a string can not be parsed into a [`Color`](crate::Color)
+///
+/// Every variant keeps the input that failed to parse.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Color, ColorParseError};
+///
+/// match "#ff88".parse::<Color>() {
+///     Err(ColorParseError::InvalidLength(input)) => println!("'{}' needs 3 or 6 digits", input),
+///     Err(error) => println!("{}", error),
+///     Ok(color) => println!("{}", color),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// A hex color without 3 or 6 digits
+    InvalidLength(String),
+    /// A hex color with a character that is not a hex digit
+    InvalidDigit(String),
+    /// A functional color like `rgb()` or `hsl()` with an unknown function or bad arguments
+    InvalidFunction {
+        /// The string that failed to parse
+        input: String,
+        /// What is wrong with the function
+        reason: String,
+    },
+    /// A string that is not a hex, functional or named color
+    UnknownColor(String),
+}
+
+impl std::fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorParseError::InvalidLength(input) => {
+                write!(f, "Invalid hex color '{}': it must have 3 or 6 digits", input)
+            }
+            ColorParseError::InvalidDigit(input) => {
+                write!(f, "Invalid hex color '{}': it must only contain hex digits", input)
+            }
+            ColorParseError::InvalidFunction { input, reason } => write!(f, "Invalid color '{}': {}", input, reason),
+            ColorParseError::UnknownColor(input) => {
+                write!(f, "Invalid color '{}': it is not a hex, functional or named color", input)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    #[test]
+    fn test_color_parse_error() {
+        assert_eq!(Color::from_hex("#12345"), Err(ColorParseError::InvalidLength("#12345".to_string())));
+        assert_eq!("#12g".parse::<Color>(), Err(ColorParseError::InvalidDigit("#12g".to_string())));
+        assert_eq!("not a color".parse::<Color>(), Err(ColorParseError::UnknownColor("not a color".to_string())));
+        assert!(matches!("rgb(1, 2)".parse::<Color>(), Err(ColorParseError::InvalidFunction { .. })));
+        assert_eq!(
+            "lab(50, 0, 0)".parse::<Color>().unwrap_err().to_string(),
+            "Invalid color 'lab(50, 0, 0)': unknown color function lab()"
+        );
+    }
+
+    #[test]
+    fn test_question_mark_into_box_error() {
+        fn parse(input: &str) -> Result<Color, Box<dyn std::error::Error>> {
+            Ok(input.parse::<Color>()?)
+        }
+
+        assert!(parse("#ff8800").is_ok());
+        assert!(parse("#ff88").is_err());
+    }
+}
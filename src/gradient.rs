@@ -0,0 +1,690 @@
+// gradient.rs
+#[cfg(feature = "ansi")]
+use crate::utils::graphemes;
+use crate::{Color, ColorMode, Interpolation};
+
+/// A sequence of color stops, giving a color for every position from `0.0` to `1.0`
+///
+/// The stops are evenly spaced with [`Gradient::new`], or placed at their own positions
+/// with [`Gradient::with_stops`] to give some colors more room than others.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Color, Gradient};
+///
+/// let sunset = Gradient::new(&[(255, 94, 77), (255, 154, 0), (237, 117, 155), (84, 56, 138)]);
+/// assert_eq!(sunset.at(0.0), Color::rgb(255, 94, 77));
+/// assert_eq!(sunset.at(1.0), Color::rgb(84, 56, 138));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "GradientRepr"))]
+pub struct Gradient {
+    /// The stops sorted by position, every position between `0.0` and `1.0` and never `-0.0`
+    stops: Vec<(f32, Color)>,
+    interpolation: Interpolation,
+    direction: GradientDirection,
+    unit: ColorUnit,
+    easing: Easing,
+}
+
+/// The fields of a deserialized [`Gradient`], before its stops are checked and sorted
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct GradientRepr {
+    stops: Vec<(f32, Color)>,
+    interpolation: Interpolation,
+    direction: GradientDirection,
+    unit: ColorUnit,
+    easing: Easing,
+}
+
+#[cfg(feature = "serde")]
+impl Default for GradientRepr {
+    fn default() -> GradientRepr {
+        let Gradient { stops, interpolation, direction, unit, easing } = Gradient::default();
+        GradientRepr { stops, interpolation, direction, unit, easing }
+    }
+}
+
+/// Rejects the stops at a NaN position, the other ones are clamped and sorted like [`Gradient::with_stops`]
+#[cfg(feature = "serde")]
+impl TryFrom<GradientRepr> for Gradient {
+    type Error = String;
+
+    fn try_from(repr: GradientRepr) -> Result<Gradient, String> {
+        if repr.stops.iter().any(|(position, _)| position.is_nan()) {
+            return Err("a gradient stop has a NaN position".to_string());
+        }
+        let GradientRepr { stops, interpolation, direction, unit, easing } = repr;
+        Ok(Gradient { stops: sorted_stops(stops), interpolation, direction, unit, easing })
+    }
+}
+
+/// Return the stops without the NaN positions, clamped between `0.0` and `1.0` and sorted by position
+fn sorted_stops(stops: impl IntoIterator<Item = (f32, Color)>) -> Vec<(f32, Color)> {
+    // Adding 0.0 turns -0.0 into 0.0, so equal gradients have the same hash
+    let mut stops: Vec<(f32, Color)> = stops
+        .into_iter()
+        .filter(|(position, _)| !position.is_nan())
+        .map(|(position, color)| (position.clamp(0.0, 1.0) + 0.0, color))
+        .collect();
+    stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+    stops
+}
+
+/// How the position along a gradient progresses, shaping where the colors change the most
+///
+/// # Variants
+/// - `Linear`: At a constant speed, the default
+/// - `EaseIn`: Slow at the start and fast at the end, the first color takes more room
+/// - `EaseOut`: Fast at the start and slow at the end, the last color takes more room
+/// - `Sine`: Slow at both ends and fast in the middle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Easing {
+    /// At a constant speed
+    #[default]
+    Linear,
+    /// Slow at the start
+    EaseIn,
+    /// Slow at the end
+    EaseOut,
+    /// Slow at both ends
+    Sine,
+}
+
+impl Easing {
+    /// Return the eased position of `t`, both between `0.0` and `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Easing;
+    ///
+    /// assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+    /// ```
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::Sine => (1.0 - (t * std::f32::consts::PI).cos()) / 2.0,
+        }
+    }
+}
+
+/// The part of a text taking a single color
+///
+/// # Variants
+/// - `Char`: Every character has its own color, the default
+/// - `Word`: Every word, the spaces between words keep the color of the previous word
+/// - `Line`: Every line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum ColorUnit {
+    /// Every character
+    #[default]
+    Char,
+    /// Every word
+    Word,
+    /// Every line
+    Line,
+}
+
+/// How a gradient goes across a text, which matters for text with several lines
+///
+/// # Variants
+/// - `Horizontal`: Through the characters one after the other, the default
+/// - `Vertical`: From the first line to the last one, every line has a single color
+/// - `Diagonal`: From the top left corner of the text to its bottom right corner, like a
+///   single surface for ASCII art
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum GradientDirection {
+    /// Through the characters one after the other
+    #[default]
+    Horizontal,
+    /// From the first line to the last one
+    Vertical,
+    /// From the top left corner to the bottom right corner
+    Diagonal,
+}
+
+/// The positions are never NaN
+impl Eq for Gradient {}
+
+impl std::hash::Hash for Gradient {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for (position, color) in &self.stops {
+            position.to_bits().hash(state);
+            color.hash(state);
+        }
+        self.interpolation.hash(state);
+        self.direction.hash(state);
+        self.unit.hash(state);
+        self.easing.hash(state);
+    }
+}
+
+impl Gradient {
+    /// Create a gradient with evenly spaced colors, from the first one at `0.0` to the last one at `1.0`
+    ///
+    /// # Params
+    /// - `colors`: The colors of the gradient, a single color gives a solid fill
+    pub fn new<C: Into<Color> + Copy>(colors: &[C]) -> Gradient {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        let stops = colors.iter().enumerate().map(|(i, color)| (i as f32 / last, (*color).into())).collect();
+        Gradient { stops, ..Gradient::default() }
+    }
+
+    /// Create a gradient with colors at the given positions
+    ///
+    /// Positions are clamped between `0.0` and `1.0` and the stops sorted by them, stops at a NaN
+    /// position are left out. Before the first stop and after the last one the color doesn't change.
+    ///
+    /// # Params
+    /// - `stops`: The positions and colors of the stops
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Gradient};
+    ///
+    /// // Mostly red, turning blue at the end
+    /// let gradient = Gradient::with_stops(&[(0.0, (255, 0, 0)), (0.7, (255, 0, 0)), (1.0, (0, 0, 255))]);
+    /// assert_eq!(gradient.at(0.5), Color::RED);
+    /// ```
+    pub fn with_stops<C: Into<Color> + Copy>(stops: &[(f32, C)]) -> Gradient {
+        let stops = sorted_stops(stops.iter().map(|(position, color)| (*position, (*color).into())));
+        Gradient { stops, ..Gradient::default() }
+    }
+
+    /// Set the color space used to interpolate between the stops, RGB by default
+    ///
+    /// # Params
+    /// - `interpolation`: The color space
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Gradient {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Set how the gradient goes across the text, horizontal by default
+    ///
+    /// # Params
+    /// - `direction`: The direction
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Gradient, GradientDirection, Style};
+    ///
+    /// let banner = "#####\n#   #\n#####";
+    /// let gradient = Gradient::new(&[(255, 0, 0), (0, 0, 255)]).direction(GradientDirection::Diagonal);
+    /// println!("{}", Style::new().gradient_fill(gradient).apply(banner));
+    /// ```
+    pub fn direction(mut self, direction: GradientDirection) -> Gradient {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the part of the text taking a single color of a horizontal gradient, every character by default
+    ///
+    /// A color per word reads better than a color per character for long prose.
+    ///
+    /// # Params
+    /// - `unit`: The part of the text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorUnit, Gradient, Style};
+    ///
+    /// let gradient = Gradient::new(&[(255, 0, 0), (0, 0, 255)]).unit(ColorUnit::Word);
+    /// println!("{}", Style::new().gradient_fill(gradient).apply("The quick brown fox jumps over the lazy dog"));
+    /// ```
+    pub fn unit(mut self, unit: ColorUnit) -> Gradient {
+        self.unit = unit;
+        self
+    }
+
+    /// Set the easing of the position along the gradient, linear by default
+    ///
+    /// # Params
+    /// - `easing`: The easing curve
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Easing, Gradient};
+    ///
+    /// let gradient = Gradient::new(&[Color::BLACK, Color::WHITE]).easing(Easing::EaseIn);
+    /// assert_eq!(gradient.at(0.5), Color::rgb(64, 64, 64));
+    /// ```
+    pub fn easing(mut self, easing: Easing) -> Gradient {
+        self.easing = easing;
+        self
+    }
+
+    /// Return the gradient going from its end to its start
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Gradient};
+    ///
+    /// let gradient = Gradient::new(&[Color::RED, Color::BLUE]).reverse();
+    /// assert_eq!(gradient.at(0.0), Color::BLUE);
+    /// ```
+    pub fn reverse(mut self) -> Gradient {
+        self.stops = self.stops.iter().rev().map(|(position, color)| (1.0 - position, *color)).collect();
+        self
+    }
+
+    /// Return the gradient going from its start to its end in the first half, and back to its start
+    /// in the second half, so both ends of a text get the same color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Gradient};
+    ///
+    /// let gradient = Gradient::new(&[Color::RED, Color::BLUE]).mirror();
+    /// assert_eq!(gradient.at(0.5), Color::BLUE);
+    /// assert_eq!(gradient.at(1.0), Color::RED);
+    /// ```
+    pub fn mirror(mut self) -> Gradient {
+        let first_half = self.stops.iter().map(|(position, color)| (position / 2.0, *color));
+        let second_half = self.stops.iter().rev().map(|(position, color)| (1.0 - position / 2.0, *color));
+        self.stops = first_half.chain(second_half).collect();
+        self
+    }
+
+    /// Return the stops of the gradient sorted by position
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Return the same gradient with every color of its stops replaced by `f(color)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Gradient};
+    ///
+    /// let dark = Gradient::new(&[Color::RED, Color::BLUE]).map(|color| color.darken(0.25));
+    /// assert_eq!(dark.at(0.0), Color::rgb(128, 0, 0));
+    /// ```
+    pub fn map(&self, f: impl Fn(Color) -> Color) -> Gradient {
+        let stops = self.stops.iter().map(|(position, color)| (*position, f(*color))).collect();
+        Gradient { stops, ..self.clone() }
+    }
+
+    /// Return true if the gradient has no colors
+    pub fn is_empty(&self) -> bool {
+        self.stops.is_empty()
+    }
+
+    /// Return the color at `t`, from `0.0` (start) to `1.0` (end), black for an empty gradient
+    ///
+    /// # Params
+    /// - `t`: The position, clamped between `0.0` and `1.0`
+    pub fn at(&self, t: f32) -> Color {
+        let t = self.easing.apply(t.clamp(0.0, 1.0));
+        let next = self.stops.iter().position(|(position, _)| *position >= t);
+        match next {
+            None => self.stops.last().map_or(Color::BLACK, |(_, color)| *color),
+            Some(0) => self.stops[0].1,
+            Some(next) => {
+                let (start, start_color) = self.stops[next - 1];
+                let (end, end_color) = self.stops[next];
+                self.interpolation.mix(start_color, end_color, (t - start) / (end - start))
+            }
+        }
+    }
+
+    /// Return the text painted with the gradient, written straight to the formatter when printed
+    ///
+    /// Nothing is allocated for a text without escape codes and the color code is only written
+    /// when the color changes, which makes it the fastest way to print long texts.
+    ///
+    /// # Params
+    /// - `text`: The text to paint, from the first character to the last one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Gradient;
+    /// use std::io::Write;
+    ///
+    /// let gradient = Gradient::new(&[(255, 0, 0), (0, 0, 255)]);
+    /// let mut stdout = std::io::stdout().lock();
+    /// for line in ["GET /index.html 200", "GET /missing 404"] {
+    ///     writeln!(stdout, "{}", gradient.paint(line)).unwrap();
+    /// }
+    /// ```
+    pub fn paint<'a>(&'a self, text: &'a str) -> GradientText<'a> {
+        GradientText { gradient: self, text, mode: None }
+    }
+
+    /// Return the color of the character at `position` in a text with the given layout
+    #[cfg(feature = "ansi")]
+    pub(crate) fn at_char(&self, layout: &TextLayout, position: &CharPosition) -> Color {
+        let fraction = |n: usize, count: usize| if count <= 1 { 0.0 } else { n as f32 / (count - 1) as f32 };
+        self.at(match self.direction {
+            GradientDirection::Horizontal => fraction(position.unit_index(self.unit), layout.units(self.unit)),
+            GradientDirection::Vertical => fraction(position.line, layout.lines),
+            GradientDirection::Diagonal => {
+                (fraction(position.column, layout.width) + fraction(position.line, layout.lines)) / 2.0
+            }
+        })
+    }
+}
+
+/// A text painted with a gradient, created with [`Gradient::paint`]
+///
+/// It is left plain if the gradient or the text is empty, or with [`ColorChoice::Never`](crate::ColorChoice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GradientText<'a> {
+    gradient: &'a Gradient,
+    text: &'a str,
+    mode: Option<ColorMode>,
+}
+
+impl GradientText<'_> {
+    /// Set the color mode of the codes, the global one ([`ColorMode::global`]) by default
+    ///
+    /// # Params
+    /// - `mode`: The color mode, the gradient colors are converted to the nearest color of the mode
+    pub fn mode(mut self, mode: ColorMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+}
+
+impl std::fmt::Display for GradientText<'_> {
+    #[cfg(feature = "ansi")]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mode = crate::ColorChoice::global().resolve(self.mode);
+        if mode == ColorMode::None || self.gradient.is_empty() || self.text.is_empty() {
+            return f.write_str(self.text);
+        }
+        crate::Style::new().write_gradient(f, self.text, Some(self.gradient), None, mode)
+    }
+
+    /// Write only the text, the `ansi` feature is disabled
+    #[cfg(not(feature = "ansi"))]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+/// How a list of colors is repeated along a text
+///
+/// Used by [`ColorPrint::polychrome_with`](crate::ColorPrint::polychrome_with).
+///
+/// # Variants
+/// - `Cycle`: Start again from the first color after the last one, the default
+/// - `Mirror`: Go back and forth through the colors, `a b c b a b c ...`
+/// - `Stretch`: The colors spread evenly over the whole text, each one used once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Repeat {
+    /// Start again from the first color after the last one
+    #[default]
+    Cycle,
+    /// Go back and forth through the colors
+    Mirror,
+    /// Every color once, spread evenly over the whole text
+    Stretch,
+}
+
+impl Repeat {
+    /// Return the index of the color of chunk number `chunk`, out of `chunks` chunks and `colors` colors
+    #[cfg(feature = "ansi")]
+    pub(crate) fn color_index(&self, chunk: usize, chunks: usize, colors: usize) -> usize {
+        match self {
+            Repeat::Cycle => chunk % colors,
+            Repeat::Mirror if colors == 1 => 0,
+            Repeat::Mirror => {
+                let period = 2 * (colors - 1);
+                let step = chunk % period;
+                step.min(period - step)
+            }
+            Repeat::Stretch => (chunk * colors / chunks.max(1)).min(colors - 1),
+        }
+    }
+}
+
+/// The size of a text, giving the position of its characters in a gradient
+#[cfg(feature = "ansi")]
+pub(crate) struct TextLayout {
+    /// The number of characters, line breaks included, see [`utils::graphemes`](crate::utils::graphemes)
+    chars: usize,
+    words: usize,
+    lines: usize,
+    /// The number of characters of the longest line
+    width: usize,
+}
+
+#[cfg(feature = "ansi")]
+impl TextLayout {
+    /// Measure a text without escape codes
+    pub(crate) fn new(plain: &str) -> TextLayout {
+        TextLayout {
+            chars: graphemes(plain).count(),
+            words: plain.split_whitespace().count(),
+            lines: plain.split('\n').count(),
+            width: plain.split('\n').map(|line| graphemes(line).count()).max().unwrap_or(0),
+        }
+    }
+
+    /// Return the number of characters, words or lines
+    pub(crate) fn units(&self, unit: ColorUnit) -> usize {
+        match unit {
+            ColorUnit::Char => self.chars,
+            ColorUnit::Word => self.words,
+            ColorUnit::Line => self.lines,
+        }
+    }
+}
+
+/// Where a character is in a text
+#[cfg(feature = "ansi")]
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CharPosition {
+    pub(crate) index: usize,
+    /// The index of its word, or of the previous word for spaces
+    pub(crate) word: usize,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+#[cfg(feature = "ansi")]
+impl CharPosition {
+    /// Return the index of the character, word or line
+    pub(crate) fn unit_index(&self, unit: ColorUnit) -> usize {
+        match unit {
+            ColorUnit::Char => self.index,
+            ColorUnit::Word => self.word,
+            ColorUnit::Line => self.line,
+        }
+    }
+}
+
+/// Gives the position of every character of a text, fed one character after the other
+#[cfg(feature = "ansi")]
+#[derive(Debug, Default)]
+pub(crate) struct CharPositions {
+    next: CharPosition,
+    words: usize,
+    in_word: bool,
+}
+
+#[cfg(feature = "ansi")]
+impl CharPositions {
+    /// Return the position of `grapheme`, the character after the previous one
+    pub(crate) fn position_of(&mut self, grapheme: &str) -> CharPosition {
+        if grapheme.chars().all(char::is_whitespace) {
+            self.in_word = false;
+        } else if !self.in_word {
+            self.in_word = true;
+            self.words += 1;
+        }
+        let position = CharPosition { word: self.words.saturating_sub(1), ..self.next };
+
+        self.next.index += 1;
+        if grapheme.ends_with('\n') {
+            (self.next.line, self.next.column) = (self.next.line + 1, 0);
+        } else {
+            self.next.column += 1;
+        }
+        position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient_at() {
+        let gradient = Gradient::new(&[Color::RED, Color::LIME, Color::BLUE]);
+        assert_eq!(gradient.at(0.0), Color::RED);
+        assert_eq!(gradient.at(0.25), Color::rgb(128, 128, 0));
+        assert_eq!(gradient.at(0.5), Color::LIME);
+        assert_eq!(gradient.at(1.0), Color::BLUE);
+        assert_eq!(gradient.at(2.0), Color::BLUE);
+
+        assert_eq!(Gradient::new(&[Color::RED]).at(0.7), Color::RED);
+        assert_eq!(Gradient::new::<Color>(&[]).at(0.5), Color::BLACK);
+    }
+
+    #[test]
+    fn test_gradient_with_stops() {
+        let gradient = Gradient::with_stops(&[(1.0, Color::BLUE), (0.5, Color::RED), (f32::NAN, Color::RED)]);
+        assert_eq!(gradient.stops(), &[(0.5, Color::RED), (1.0, Color::BLUE)]);
+        assert_eq!(gradient.at(0.25), Color::RED);
+        assert_eq!(gradient.at(0.75), Color::rgb(128, 0, 128));
+
+        // -0.0 is stored as 0.0, so the gradients equal to each other have the same hash
+        let negative = Gradient::with_stops(&[(-0.0, Color::RED)]);
+        assert_eq!(negative.stops()[0].0.to_bits(), 0.0f32.to_bits());
+        let hash = |gradient: &Gradient| {
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            gradient.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&negative), hash(&Gradient::with_stops(&[(0.0, Color::RED)])));
+
+        let gradient = Gradient::with_stops(&[(0.2, Color::RED), (0.8, Color::BLUE)]);
+        assert_eq!(gradient.at(0.1), Color::RED);
+        assert_eq!(gradient.at(0.9), Color::BLUE);
+    }
+
+    /// The color of every character of `text`
+    #[cfg(feature = "ansi")]
+    fn char_colors(gradient: &Gradient, text: &str) -> Vec<Color> {
+        let layout = TextLayout::new(text);
+        let mut positions = CharPositions::default();
+        graphemes(text).map(|grapheme| gradient.at_char(&layout, &positions.position_of(grapheme))).collect()
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_directions() {
+        let (red, purple, blue) = (Color::RED, Color::rgb(128, 0, 128), Color::BLUE);
+        let gradient = Gradient::new(&[red, blue]);
+        let colors = char_colors(&gradient, "abc\nde\nfgh");
+        assert_eq!((colors[0], colors[9]), (red, blue));
+
+        let vertical = gradient.clone().direction(GradientDirection::Vertical);
+        assert_eq!(char_colors(&vertical, "ab\ncd\nef"), [red, red, red, purple, purple, purple, blue, blue]);
+
+        let diagonal = gradient.direction(GradientDirection::Diagonal);
+        let colors = char_colors(&diagonal, "abc\nde\nfgh");
+        assert_eq!((colors[0], colors[2], colors[9]), (red, purple, blue));
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_units() {
+        let (red, purple, blue) = (Color::RED, Color::rgb(128, 0, 128), Color::BLUE);
+        let gradient = Gradient::new(&[red, blue]);
+
+        let words = gradient.clone().unit(ColorUnit::Word);
+        assert_eq!(char_colors(&words, " ab c  de"), [red, red, red, red, purple, purple, purple, blue, blue]);
+
+        let lines = gradient.unit(ColorUnit::Line);
+        assert_eq!(char_colors(&lines, "a\nb"), [red, red, blue]);
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_repeat_color_index() {
+        let indexes = |repeat: Repeat, chunks: usize| -> Vec<usize> {
+            (0..chunks).map(|chunk| repeat.color_index(chunk, chunks, 3)).collect()
+        };
+        assert_eq!(indexes(Repeat::Cycle, 7), [0, 1, 2, 0, 1, 2, 0]);
+        assert_eq!(indexes(Repeat::Mirror, 7), [0, 1, 2, 1, 0, 1, 2]);
+        assert_eq!(indexes(Repeat::Stretch, 7), [0, 0, 0, 1, 1, 2, 2]);
+        assert_eq!(Repeat::Mirror.color_index(5, 7, 1), 0);
+    }
+
+    #[test]
+    fn test_gradient_easing() {
+        let gradient = Gradient::new(&[Color::BLACK, Color::WHITE]);
+        assert_eq!(gradient.clone().easing(Easing::EaseIn).at(0.5), Color::rgb(64, 64, 64));
+        assert_eq!(gradient.clone().easing(Easing::EaseOut).at(0.5), Color::rgb(191, 191, 191));
+        assert_eq!(gradient.clone().easing(Easing::Sine).at(0.5), gradient.at(0.5));
+        assert_eq!(gradient.clone().easing(Easing::Sine).at(1.0), Color::WHITE);
+
+        let stops = Gradient::with_stops(&[(0.0, Color::RED), (0.25, Color::LIME), (1.0, Color::BLUE)]);
+        assert_eq!(stops.clone().reverse().stops(), [(0.0, Color::BLUE), (0.75, Color::LIME), (1.0, Color::RED)]);
+        assert_eq!(stops.clone().reverse().reverse(), stops);
+        let mirrored = stops.mirror();
+        assert_eq!([mirrored.at(0.125), mirrored.at(0.875)], [Color::LIME, Color::LIME]);
+        assert_eq!([mirrored.at(0.0), mirrored.at(0.5), mirrored.at(1.0)], [Color::RED, Color::BLUE, Color::RED]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_gradient_serde() {
+        let gradient = Gradient::with_stops(&[(0.0, Color::RED), (0.25, Color::BLUE)]).easing(Easing::EaseIn);
+        let json = serde_json::to_string(&gradient).unwrap();
+        assert_eq!(serde_json::from_str::<Gradient>(&json).unwrap(), gradient);
+
+        // The stops are clamped and sorted like with `Gradient::with_stops`
+        let parsed: Gradient = serde_json::from_str(r##"{"stops": [[2.0, "#0000ff"], [-0.0, "#ff0000"]]}"##).unwrap();
+        assert_eq!(parsed, Gradient::new(&[Color::RED, Color::BLUE]));
+        assert_eq!(parsed.stops()[0].0.to_bits(), 0.0f32.to_bits());
+
+        let repr = GradientRepr { stops: vec![(f32::NAN, Color::RED)], ..GradientRepr::default() };
+        assert!(Gradient::try_from(repr).is_err());
+    }
+
+    #[test]
+    fn test_gradient_paint() {
+        let gradient = Gradient::new(&[Color::RED, Color::BLUE]);
+        assert_eq!(gradient.paint("").to_string(), "");
+        assert_eq!(Gradient::default().paint("plain").to_string(), "plain");
+
+        #[cfg(feature = "ansi")]
+        {
+            let mode = ColorMode::global_guard(ColorMode::TrueColor);
+            assert_eq!(gradient.paint("ab").to_string(), "\x1b[38;2;255;0;0ma\x1b[38;2;0;0;255mb\x1b[0m");
+            assert_eq!(gradient.paint("aaa").to_string(), crate::ColorPrint::gradient("aaa", Color::RED, Color::BLUE));
+            let solid = Gradient::new(&[Color::RED]).paint("abc").mode(ColorMode::Ansi16).to_string();
+            assert_eq!(solid, "\x1b[91mabc\x1b[0m");
+            drop(mode);
+
+            // Without its own mode the text follows the global one
+            let _mode = ColorMode::global_guard(ColorMode::Ansi256);
+            assert_eq!(gradient.paint("ab").to_string(), "\x1b[38;5;196ma\x1b[38;5;21mb\x1b[0m");
+        }
+        #[cfg(not(feature = "ansi"))]
+        assert_eq!(gradient.paint("ab").to_string(), "ab");
+    }
+}
@@ -0,0 +1,213 @@
+// gradients.rs
+//! Ready to use gradients for banners and splash screens
+//!
+//! # Example
+//!
+//! ```
+//! use polychrome::gradients::Preset;
+//! use polychrome::StyleExt;
+//!
+//! println!("{}", "Welcome!".gradient_preset(Preset::Sunset).bold());
+//! ```
+use crate::{Color, Gradient};
+
+/// A named gradient
+///
+/// The flags repeat their stripes as stops, so the colors blend into each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// Red, orange, yellow, green, blue, indigo and violet
+    Rainbow,
+    /// Coral to orange, pink and purple
+    Sunset,
+    /// Deep navy to light cyan
+    Ocean,
+    /// Dark red to orange and pale yellow
+    Fire,
+    /// Soft pink, peach, yellow, mint and sky blue
+    Pastel,
+    /// The six stripes of the rainbow pride flag
+    Pride,
+    /// The transgender pride flag
+    Trans,
+    /// The bisexual pride flag
+    Bisexual,
+    /// The lesbian pride flag
+    Lesbian,
+    /// The nonbinary pride flag
+    Nonbinary,
+}
+
+impl Preset {
+    /// Every preset
+    pub const ALL: [Preset; 10] = [
+        Preset::Rainbow,
+        Preset::Sunset,
+        Preset::Ocean,
+        Preset::Fire,
+        Preset::Pastel,
+        Preset::Pride,
+        Preset::Trans,
+        Preset::Bisexual,
+        Preset::Lesbian,
+        Preset::Nonbinary,
+    ];
+
+    /// Return the lowercase name of the preset, e.g. `sunset`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Preset::Rainbow => "rainbow",
+            Preset::Sunset => "sunset",
+            Preset::Ocean => "ocean",
+            Preset::Fire => "fire",
+            Preset::Pastel => "pastel",
+            Preset::Pride => "pride",
+            Preset::Trans => "trans",
+            Preset::Bisexual => "bisexual",
+            Preset::Lesbian => "lesbian",
+            Preset::Nonbinary => "nonbinary",
+        }
+    }
+
+    /// Return the colors of the preset, from the start to the end
+    pub fn colors(&self) -> &'static [Color] {
+        match self {
+            Preset::Rainbow => &RAINBOW,
+            Preset::Sunset => &SUNSET,
+            Preset::Ocean => &OCEAN,
+            Preset::Fire => &FIRE,
+            Preset::Pastel => &PASTEL,
+            Preset::Pride => &PRIDE,
+            Preset::Trans => &TRANS,
+            Preset::Bisexual => &BISEXUAL,
+            Preset::Lesbian => &LESBIAN,
+            Preset::Nonbinary => &NONBINARY,
+        }
+    }
+
+    /// Return the gradient of the preset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::gradients::Preset;
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Preset::Rainbow.gradient().at(0.0), Color::RED);
+    /// ```
+    pub fn gradient(&self) -> Gradient {
+        Gradient::new(self.colors())
+    }
+}
+
+impl From<Preset> for Gradient {
+    fn from(preset: Preset) -> Gradient {
+        preset.gradient()
+    }
+}
+
+const RAINBOW: [Color; 7] = [
+    Color::rgb(255, 0, 0),
+    Color::rgb(255, 127, 0),
+    Color::rgb(255, 255, 0),
+    Color::rgb(0, 255, 0),
+    Color::rgb(0, 0, 255),
+    Color::rgb(75, 0, 130),
+    Color::rgb(148, 0, 211),
+];
+
+const SUNSET: [Color; 4] = [
+    Color::rgb(255, 94, 77),
+    Color::rgb(255, 154, 0),
+    Color::rgb(237, 117, 155),
+    Color::rgb(84, 56, 138),
+];
+
+const OCEAN: [Color; 4] = [
+    Color::rgb(0, 32, 96),
+    Color::rgb(0, 119, 182),
+    Color::rgb(0, 180, 216),
+    Color::rgb(144, 224, 239),
+];
+
+const FIRE: [Color; 4] = [
+    Color::rgb(128, 0, 0),
+    Color::rgb(255, 69, 0),
+    Color::rgb(255, 165, 0),
+    Color::rgb(255, 255, 102),
+];
+
+const PASTEL: [Color; 5] = [
+    Color::rgb(255, 179, 186),
+    Color::rgb(255, 223, 186),
+    Color::rgb(255, 255, 186),
+    Color::rgb(186, 255, 201),
+    Color::rgb(186, 225, 255),
+];
+
+const PRIDE: [Color; 6] = [
+    Color::rgb(228, 3, 3),
+    Color::rgb(255, 140, 0),
+    Color::rgb(255, 237, 0),
+    Color::rgb(0, 128, 38),
+    Color::rgb(0, 77, 255),
+    Color::rgb(117, 7, 135),
+];
+
+const TRANS: [Color; 5] = [
+    Color::rgb(91, 206, 250),
+    Color::rgb(245, 169, 184),
+    Color::rgb(255, 255, 255),
+    Color::rgb(245, 169, 184),
+    Color::rgb(91, 206, 250),
+];
+
+const BISEXUAL: [Color; 3] = [
+    Color::rgb(214, 2, 112),
+    Color::rgb(155, 79, 150),
+    Color::rgb(0, 56, 168),
+];
+
+const LESBIAN: [Color; 5] = [
+    Color::rgb(213, 45, 0),
+    Color::rgb(255, 154, 86),
+    Color::rgb(255, 255, 255),
+    Color::rgb(211, 98, 164),
+    Color::rgb(163, 2, 98),
+];
+
+const NONBINARY: [Color; 4] = [
+    Color::rgb(252, 244, 52),
+    Color::rgb(255, 255, 255),
+    Color::rgb(156, 89, 209),
+    Color::rgb(44, 44, 44),
+];
+
+/// Look up a preset by name, ignoring case
+///
+/// # Example
+///
+/// ```
+/// use polychrome::gradients::{self, Preset};
+///
+/// assert_eq!(gradients::by_name("Ocean"), Some(Preset::Ocean));
+/// assert_eq!(gradients::by_name("plaid"), None);
+/// ```
+pub fn by_name(name: &str) -> Option<Preset> {
+    Preset::ALL.iter().find(|preset| preset.name().eq_ignore_ascii_case(name)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        for preset in Preset::ALL {
+            assert!(preset.colors().len() >= 3, "{} has too few colors", preset.name());
+            assert_eq!(by_name(preset.name()), Some(preset));
+            assert_eq!(Gradient::from(preset).at(1.0), *preset.colors().last().unwrap());
+        }
+        assert_eq!(Preset::Trans.gradient().at(0.5), Color::WHITE);
+    }
+}
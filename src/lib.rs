@@ -1,4 +1,32 @@
+#[macro_use]
+mod ansi_color;
 mod color;
+pub mod colors;
+mod effects;
+mod error;
+mod gradient;
+pub mod gradients;
+mod palette;
+mod panel;
+mod progress;
+mod rgb;
+mod spinner;
+mod style;
+mod styled_line;
+mod table;
+pub mod utils;
 
 // Exporta los tipos y funciones del módulo color.rs para que sean públicos desde el crate.
-pub use color::*;
\ No newline at end of file
+pub use ansi_color::*;
+pub use color::*;
+pub use effects::*;
+pub use error::*;
+pub use gradient::*;
+pub use palette::*;
+pub use panel::*;
+pub use progress::*;
+pub use rgb::*;
+pub use spinner::*;
+pub use style::*;
+pub use styled_line::*;
+pub use table::*;
\ No newline at end of file
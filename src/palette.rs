@@ -0,0 +1,137 @@
+// palette.rs
+use crate::Color;
+
+/// A collection of named colors
+///
+/// Colors keep their insertion order, can be looked up by name and handed out
+/// cyclically, so multi-series output (charts, progress bars, per-thread logs)
+/// pulls consistent colors from one place.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{colors, ColorPrintExt, Palette};
+///
+/// let palette = Palette::new()
+///     .with("info", colors::DODGER_BLUE)
+///     .with("warning", colors::ORANGE)
+///     .with("error", colors::CRIMSON);
+///
+/// println!("{}", "Disk almost full".fg(palette.get("warning").unwrap()));
+///
+/// for (thread, color) in (0..5).zip(palette.cycle()) {
+///     println!("{}", format!("thread {}", thread).as_str().fg(color));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Palette {
+    entries: Vec<(String, Color)>,
+}
+
+impl Palette {
+    /// Create an empty palette
+    pub fn new() -> Palette {
+        Palette::default()
+    }
+
+    /// Add a named color, replacing the color of an existing entry with the same name
+    ///
+    /// # Params
+    /// - `name`: The name of the color
+    /// - `color`: The color
+    pub fn with(mut self, name: &str, color: impl Into<Color>) -> Palette {
+        self.insert(name, color);
+        self
+    }
+
+    /// Add a named color, replacing the color of an existing entry with the same name
+    ///
+    /// # Params
+    /// - `name`: The name of the color
+    /// - `color`: The color
+    pub fn insert(&mut self, name: &str, color: impl Into<Color>) {
+        let color = color.into();
+        match self.entries.iter_mut().find(|(entry, _)| entry == name) {
+            Some(entry) => entry.1 = color,
+            None => self.entries.push((name.to_string(), color)),
+        }
+    }
+
+    /// Get the color with the given name
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.entries.iter().find(|(entry, _)| entry == name).map(|(_, color)| *color)
+    }
+
+    /// Get the color at `index`, wrapping around the end of the palette
+    ///
+    /// Useful to give series number `index` always the same color.
+    pub fn pick(&self, index: usize) -> Option<Color> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        Some(self.entries[index % self.entries.len()].1)
+    }
+
+    /// Return the number of colors
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return true if the palette has no colors
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the names and colors in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.entries.iter().map(|(name, color)| (name.as_str(), *color))
+    }
+
+    /// Iterate over the colors endlessly, starting again after the last one
+    ///
+    /// The iterator is empty if the palette is empty.
+    pub fn cycle(&self) -> impl Iterator<Item = Color> + '_ {
+        self.entries.iter().map(|(_, color)| *color).cycle()
+    }
+}
+
+impl<S: Into<String>, C: Into<Color>> FromIterator<(S, C)> for Palette {
+    fn from_iter<I: IntoIterator<Item = (S, C)>>(iter: I) -> Palette {
+        let mut palette = Palette::new();
+        for (name, color) in iter {
+            palette.insert(&name.into(), color);
+        }
+        palette
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette() {
+        let mut palette = Palette::new().with("red", Color::RED).with("green", (0, 255, 0));
+        palette.insert("blue", Color::BLUE);
+        palette.insert("red", (200, 0, 0));
+
+        assert_eq!(palette.len(), 3);
+        assert_eq!(palette.get("red"), Some(Color::rgb(200, 0, 0)));
+        assert_eq!(palette.get("yellow"), None);
+        assert_eq!(palette.pick(4), Some(Color::LIME));
+        assert_eq!(
+            palette.cycle().take(4).collect::<Vec<_>>(),
+            [Color::rgb(200, 0, 0), Color::LIME, Color::BLUE, Color::rgb(200, 0, 0)]
+        );
+        assert_eq!(palette.iter().map(|(name, _)| name).collect::<Vec<_>>(), ["red", "green", "blue"]);
+    }
+
+    #[test]
+    fn test_empty_palette() {
+        let palette: Palette = Vec::<(&str, Color)>::new().into_iter().collect();
+
+        assert!(palette.is_empty());
+        assert_eq!(palette.pick(0), None);
+        assert_eq!(palette.cycle().next(), None);
+    }
+}
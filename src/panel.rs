@@ -0,0 +1,204 @@
+// panel.rs
+use crate::table::{align, paint};
+use crate::utils::{display_width, escape_len};
+use crate::{Alignment, BorderStyle, Style};
+use std::fmt::Display;
+
+/// Text framed by a border, with an optional title in the top line
+///
+/// The content can have several lines and its own styles, a style running over a line break
+/// is stopped before the border and started again on the next line.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Alignment, ColorPrintExt, Panel, Style};
+///
+/// let panel = Panel::new("The configuration file is missing,\nthe defaults are used.")
+///     .title("warning".color(255, 200, 0).bold())
+///     .padding(2, 1)
+///     .alignment(Alignment::Center)
+///     .border_style(Style::new().color(255, 200, 0));
+/// println!("{}", panel);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Panel {
+    content: String,
+    title: Option<String>,
+    padding: (usize, usize),
+    alignment: Alignment,
+    border: BorderStyle,
+    border_style: Style,
+    width: usize,
+}
+
+impl Panel {
+    /// Create a panel with rounded borders and a padding of 1 space on both sides
+    ///
+    /// # Params
+    /// - `content`: The text inside the panel, anything printable
+    pub fn new(content: impl Display) -> Panel {
+        Panel { content: content.to_string(), padding: (1, 0), ..Panel::default() }
+    }
+
+    /// Set the title printed in the top border
+    pub fn title(mut self, title: impl Display) -> Panel {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Set the number of spaces on the left and right of the content and of empty lines above and below it
+    pub fn padding(mut self, horizontal: usize, vertical: usize) -> Panel {
+        self.padding = (horizontal, vertical);
+        self
+    }
+
+    /// Set the alignment of the content lines, left by default
+    pub fn alignment(mut self, alignment: Alignment) -> Panel {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the lines drawn around the content
+    pub fn border(mut self, border: BorderStyle) -> Panel {
+        self.border = border;
+        self
+    }
+
+    /// Set the style of the border, e.g. its color
+    pub fn border_style(mut self, style: Style) -> Panel {
+        self.border_style = style;
+        self
+    }
+
+    /// Set the minimum width of the panel, borders included
+    pub fn width(mut self, width: usize) -> Panel {
+        self.width = width;
+        self
+    }
+}
+
+impl Display for Panel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = styled_lines(&self.content);
+        let title = self.title.as_ref().map(|title| format!(" {} ", title));
+        let title_width = title.as_deref().map_or(0, display_width);
+        let chars = self.border.chars();
+        // The room taken by the borders, and by the title in the top border
+        let (borders, title_room) = if chars.is_some() { (2, title_width + 2) } else { (0, 0) };
+
+        // The width between the borders
+        let inner = lines.iter().map(|line| display_width(line)).max().unwrap_or(0) + 2 * self.padding.0;
+        let inner = inner.max(title_room).max(self.width.saturating_sub(borders));
+        let content_width = inner - 2 * self.padding.0;
+        let padding = " ".repeat(self.padding.0);
+        let vertical = match &chars {
+            Some(chars) => paint(&self.border_style, &chars.vertical.to_string()),
+            None => String::new(),
+        };
+
+        if let Some(chars) = &chars {
+            let horizontal = |count: usize| chars.horizontal.to_string().repeat(count);
+            match &title {
+                Some(title) => {
+                    let left = paint(&self.border_style, &format!("{}{}", chars.top[0], horizontal(1)));
+                    let right = format!("{}{}", horizontal(inner - 1 - title_width), chars.top[2]);
+                    writeln!(f, "{}{}{}", left, title, paint(&self.border_style, &right))?;
+                }
+                None => {
+                    let top = format!("{}{}{}", chars.top[0], horizontal(inner), chars.top[2]);
+                    writeln!(f, "{}", paint(&self.border_style, &top))?;
+                }
+            }
+        } else if let Some(title) = &title {
+            writeln!(f, "{}", title.trim())?;
+        }
+
+        let blank = format!("{}{}{}", vertical, " ".repeat(inner), vertical);
+        for _ in 0..self.padding.1 {
+            writeln!(f, "{}", blank)?;
+        }
+        for line in &lines {
+            let line = align(line, content_width, self.alignment);
+            writeln!(f, "{}{}{}{}{}", vertical, padding, line, padding, vertical)?;
+        }
+        for _ in 0..self.padding.1 {
+            writeln!(f, "{}", blank)?;
+        }
+
+        if let Some(chars) = &chars {
+            let horizontal = chars.horizontal.to_string().repeat(inner);
+            let bottom = format!("{}{}{}", chars.bottom[0], horizontal, chars.bottom[2]);
+            writeln!(f, "{}", paint(&self.border_style, &bottom))?;
+        }
+        Ok(())
+    }
+}
+
+/// Split a styled text into lines that each start and end their own styles
+///
+/// The escape codes in effect at the end of a line are written again at the start of the next
+/// one, and a reset ends every line still styled.
+fn styled_lines(text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let (mut line, mut state) = (String::new(), String::new());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if c == '\x1b' {
+            let escape = &rest[..escape_len(rest)];
+            if escape == "\x1b[0m" || escape == "\x1b[m" {
+                state.clear();
+            } else {
+                state.push_str(escape);
+            }
+            line.push_str(escape);
+            rest = &rest[escape.len()..];
+            continue;
+        }
+        if c == '\n' {
+            if !state.is_empty() {
+                line.push_str("\x1b[0m");
+            }
+            lines.push(std::mem::replace(&mut line, state.clone()));
+        } else {
+            line.push(c);
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    lines.push(line);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panel() {
+        let panel = Panel::new("Hello\nworld!").title("greeting").padding(1, 1).alignment(Alignment::Center);
+        let expected = "\
+╭─ greeting ─╮
+│            │
+│   Hello    │
+│   world!   │
+│            │
+╰────────────╯
+";
+        assert_eq!(panel.to_string(), expected);
+
+        let panel = Panel::new("ok").border(BorderStyle::Ascii).width(8);
+        assert_eq!(panel.to_string(), "+------+\n| ok   |\n+------+\n");
+        assert_eq!(Panel::new("a").border(BorderStyle::None).title("t").to_string(), "t\n a \n");
+    }
+
+    #[test]
+    fn test_styled_lines() {
+        assert_eq!(styled_lines("a\nb"), ["a", "b"]);
+        assert_eq!(styled_lines("\x1b[31ma\nb\x1b[0m\nc"), ["\x1b[31ma\x1b[0m", "\x1b[31mb\x1b[0m", "c"]);
+
+        let red = Style::new().color(255, 0, 0).mode(crate::ColorMode::TrueColor);
+        let panel = Panel::new(red.apply("x\ny")).border_style(red.clone()).to_string();
+        let plain: Vec<String> = panel.lines().map(crate::utils::strip_ansi).collect();
+        assert_eq!(plain, ["╭───╮", "│ x │", "│ y │", "╰───╯"]);
+    }
+}
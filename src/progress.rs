@@ -0,0 +1,125 @@
+// progress.rs
+use crate::table::paint;
+use crate::Style;
+use std::fmt::Display;
+
+/// A progress bar followed by its percentage
+///
+/// The filled part takes the style of the bar, with a gradient it goes through every color of
+/// the gradient whatever the progress.
+///
+/// # Example
+///
+/// ```no_run
+/// use polychrome::{ProgressBar, Style};
+/// use std::io::Write;
+///
+/// let mut bar = ProgressBar::new(30).style(Style::new().color(0, 200, 255)).markers('✓', '○');
+/// for step in 0..=100 {
+///     bar.set_progress(step as f32 / 100.0);
+///     print!("\r{}", bar);
+///     std::io::stdout().flush().unwrap();
+///     std::thread::sleep(std::time::Duration::from_millis(20));
+/// }
+/// println!();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressBar {
+    width: usize,
+    progress: f32,
+    filled: char,
+    empty: char,
+    style: Style,
+    markers: Option<(char, char)>,
+}
+
+impl ProgressBar {
+    /// Create an empty, unstyled bar drawn with `█` and `░`
+    ///
+    /// # Params
+    /// - `width`: The number of characters of the bar, without the percentage
+    pub fn new(width: usize) -> ProgressBar {
+        ProgressBar { width, progress: 0.0, filled: '█', empty: '░', style: Style::new(), markers: None }
+    }
+
+    /// Set the characters of the filled and the empty parts of the bar
+    pub fn chars(mut self, filled: char, empty: char) -> ProgressBar {
+        self.filled = filled;
+        self.empty = empty;
+        self
+    }
+
+    /// Set the style of the filled part of the bar
+    pub fn style(mut self, style: Style) -> ProgressBar {
+        self.style = style;
+        self
+    }
+
+    /// Print a marker instead of the percentage when the bar is complete or empty
+    ///
+    /// # Params
+    /// - `done`: The marker of a complete bar, e.g. `✓`
+    /// - `empty`: The marker of an empty bar, e.g. `○`
+    pub fn markers(mut self, done: char, empty: char) -> ProgressBar {
+        self.markers = Some((done, empty));
+        self
+    }
+
+    /// Set the progress, from `0.0` to `1.0`
+    pub fn progress(mut self, progress: f32) -> ProgressBar {
+        self.set_progress(progress);
+        self
+    }
+
+    /// Update the progress, e.g. from a loop, it is clamped between `0.0` and `1.0`
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress = if progress.is_nan() { 0.0 } else { progress.clamp(0.0, 1.0) };
+    }
+}
+
+impl Display for ProgressBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let filled = (self.progress * self.width as f32).round() as usize;
+        if filled > 0 {
+            f.write_str(&paint(&self.style, &self.filled.to_string().repeat(filled)))?;
+        }
+        write!(f, "{}", self.empty.to_string().repeat(self.width - filled))?;
+
+        match self.markers {
+            Some((done, _)) if self.progress >= 1.0 => write!(f, " {}", done),
+            Some((_, empty)) if self.progress <= 0.0 => write!(f, " {}", empty),
+            _ => write!(f, " {:>3}%", (self.progress * 100.0).round() as u8),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_bar() {
+        let bar = ProgressBar::new(10).chars('#', '-');
+        assert_eq!(bar.to_string(), "----------   0%");
+        assert_eq!(bar.clone().progress(0.42).to_string(), "####------  42%");
+        assert_eq!(bar.clone().progress(2.0).to_string(), "########## 100%");
+        assert_eq!(ProgressBar::new(2).progress(0.5).to_string(), "█░  50%");
+    }
+
+    #[test]
+    fn test_progress_markers() {
+        let bar = ProgressBar::new(4).chars('#', '-').markers('✓', '○');
+        assert_eq!(bar.clone().progress(0.0).to_string(), "---- ○");
+        assert_eq!(bar.clone().progress(1.0).to_string(), "#### ✓");
+        assert_eq!(bar.clone().progress(0.5).to_string(), "##--  50%");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_progress_style() {
+        let red = Style::new().color(255, 0, 0).mode(crate::ColorMode::TrueColor);
+        let bar = ProgressBar::new(4).chars('#', '-').style(red).progress(0.5);
+        assert_eq!(bar.to_string(), "\x1b[38;2;255;0;0m##\x1b[0m--  50%");
+        assert_eq!(bar.progress(0.0).to_string(), "----   0%");
+    }
+}
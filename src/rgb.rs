@@ -0,0 +1,1293 @@
+// rgb.rs
+use crate::ColorParseError;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A RGB color
+///
+/// Can be built from its channels, from a hex string or from a `(r, g, b)` tuple,
+/// and is accepted by every function that takes a color.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::Color;
+///
+/// let orange = Color::rgb(255, 136, 0);
+/// assert_eq!(Color::from_hex("#ff8800"), Ok(orange));
+/// assert_eq!(Color::from((255, 136, 0)), orange);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// Red channel
+    pub r: u8,
+    /// Green channel
+    pub g: u8,
+    /// Blue channel
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const RED: Color = Color::rgb(255, 0, 0);
+    /// The CSS green, like [`colors::GREEN`](crate::colors::GREEN), the pure green is [`Color::LIME`]
+    pub const GREEN: Color = Color::rgb(0, 128, 0);
+    pub const LIME: Color = Color::rgb(0, 255, 0);
+    pub const BLUE: Color = Color::rgb(0, 0, 255);
+    pub const YELLOW: Color = Color::rgb(255, 255, 0);
+    pub const CYAN: Color = Color::rgb(0, 255, 255);
+    pub const MAGENTA: Color = Color::rgb(255, 0, 255);
+    pub const ORANGE: Color = Color::rgb(255, 165, 0);
+    pub const PURPLE: Color = Color::rgb(128, 0, 128);
+    pub const PINK: Color = Color::rgb(255, 192, 203);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+
+    /// Create a color from its channels
+    ///
+    /// # Params
+    /// - `r`: Red color value
+    /// - `g`: Green color value
+    /// - `b`: Blue color value
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// Create a color from a hex string like `#ff8800`, `ff8800` or `#f80`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::from_hex("#f80"), Ok(Color::rgb(255, 136, 0)));
+    /// assert!(Color::from_hex("#ff88").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Color, ColorParseError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidDigit(hex.to_string()));
+        }
+
+        let channel = |i: usize, len: usize| {
+            let value = u8::from_str_radix(&digits[i * len..(i + 1) * len], 16).unwrap();
+            if len == 1 { value * 17 } else { value }
+        };
+
+        match digits.len() {
+            3 => Ok(Color::rgb(channel(0, 1), channel(1, 1), channel(2, 1))),
+            6 => Ok(Color::rgb(channel(0, 2), channel(1, 2), channel(2, 2))),
+            _ => Err(ColorParseError::InvalidLength(hex.to_string())),
+        }
+    }
+
+    /// Return the color as a lowercase hex string like `#ff8800`, the inverse of [`Color::from_hex`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::rgb(255, 136, 0).to_hex(), "#ff8800");
+    /// assert_eq!(Color::rgb(30, 144, 255).to_string(), "#1e90ff");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Create a color from a hex string at compile time, like [`Color::from_hex`]
+    ///
+    /// Used in a constant an invalid hex string is a compile error, at runtime it panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// const DODGER_BLUE: Color = Color::const_hex("#1e90ff");
+    /// assert_eq!(DODGER_BLUE, Color::rgb(30, 144, 255));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use polychrome::Color;
+    ///
+    /// const BROKEN: Color = Color::const_hex("#1e90fg");
+    /// ```
+    pub const fn const_hex(hex: &str) -> Color {
+        let bytes = hex.as_bytes();
+        let start = if !bytes.is_empty() && bytes[0] == b'#' { 1 } else { 0 };
+
+        const fn digit(byte: u8) -> u8 {
+            match byte {
+                b'0'..=b'9' => byte - b'0',
+                b'a'..=b'f' => byte - b'a' + 10,
+                b'A'..=b'F' => byte - b'A' + 10,
+                _ => panic!("Invalid hex color: it must only contain hex digits"),
+            }
+        }
+
+        match bytes.len() - start {
+            3 => Color::rgb(
+                digit(bytes[start]) * 17,
+                digit(bytes[start + 1]) * 17,
+                digit(bytes[start + 2]) * 17,
+            ),
+            6 => Color::rgb(
+                digit(bytes[start]) * 16 + digit(bytes[start + 1]),
+                digit(bytes[start + 2]) * 16 + digit(bytes[start + 3]),
+                digit(bytes[start + 4]) * 16 + digit(bytes[start + 5]),
+            ),
+            _ => panic!("Invalid hex color: it must have 3 or 6 digits"),
+        }
+    }
+
+    /// Create a color from hue, saturation and lightness
+    ///
+    /// # Params
+    /// - `h`: Hue in degrees, wrapped into `0.0..360.0`
+    /// - `s`: Saturation from `0.0` to `1.0`
+    /// - `l`: Lightness from `0.0` to `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(120.0, 1.0, 0.5), Color::LIME);
+    /// ```
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Color {
+        let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue_chroma(h, chroma, l - chroma / 2.0)
+    }
+
+    /// Return the hue (degrees), saturation and lightness (`0.0` to `1.0`) of the color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// // Lighten a color
+    /// let (h, s, l) = Color::rgb(30, 144, 255).to_hsl();
+    /// println!("{:?}", Color::from_hsl(h, s, (l + 0.2).min(1.0)));
+    /// ```
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let l = (max + min) / 2.0;
+        let s = if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * l - 1.0).abs()) };
+        (hue, s, l)
+    }
+
+    /// Create a color from hue, saturation and value (also known as HSB)
+    ///
+    /// # Params
+    /// - `h`: Hue in degrees, wrapped into `0.0..360.0`
+    /// - `s`: Saturation from `0.0` to `1.0`
+    /// - `v`: Value from `0.0` to `1.0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrintExt};
+    ///
+    /// for i in 0..6 {
+    ///     print!("{}", "#".fg(Color::from_hsv(i as f32 * 60.0, 0.8, 1.0)));
+    /// }
+    /// ```
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Color {
+        let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+        let chroma = v * s;
+        from_hue_chroma(h, chroma, v - chroma)
+    }
+
+    /// Return the hue (degrees), saturation and value (`0.0` to `1.0`) of the color
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        (hue, s, max)
+    }
+
+    /// Create a color from its OKLab coordinates
+    ///
+    /// Channels out of the sRGB gamut are clamped.
+    ///
+    /// # Params
+    /// - `l`: Perceived lightness from `0.0` to `1.0`
+    /// - `a`: Green (negative) to red (positive) axis
+    /// - `b`: Blue (negative) to yellow (positive) axis
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Color {
+        let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+        Color::rgb(
+            from_linear(4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_),
+            from_linear(-1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_),
+            from_linear(-0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_),
+        )
+    }
+
+    /// Return the OKLab coordinates (`l`, `a`, `b`) of the color
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        let (r, g, b) = (to_linear(self.r), to_linear(self.g), to_linear(self.b));
+
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    /// Put `other` with the given opacity on top of this color
+    ///
+    /// # Params
+    /// - `other`: The color on top
+    /// - `alpha`: The opacity of `other`, from `0.0` (this color) to `1.0` (`other`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::BLACK.blend(Color::WHITE, 0.25), Color::rgb(64, 64, 64));
+    /// ```
+    pub fn blend(&self, other: Color, alpha: f32) -> Color {
+        Interpolation::Rgb.mix(*self, other, alpha)
+    }
+
+    /// Put this color with the given opacity on top of `background`
+    ///
+    /// Simulates a translucent color on a known background, e.g. dimmed or hover states.
+    ///
+    /// # Params
+    /// - `background`: The color below
+    /// - `alpha`: The opacity of this color, from `0.0` (`background`) to `1.0` (this color)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// let dimmed = Color::rgb(255, 136, 0).over(Color::rgb(30, 30, 30), 0.5);
+    /// assert_eq!(dimmed, Color::rgb(143, 83, 15));
+    /// ```
+    pub fn over(&self, background: Color, alpha: f32) -> Color {
+        background.blend(*self, alpha)
+    }
+
+    /// Increase the HSL lightness by `amount` (`0.0` to `1.0`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// let base = Color::rgb(30, 144, 255);
+    /// let hover = base.lighten(0.1);
+    /// let disabled = base.desaturate(0.6).darken(0.1);
+    /// assert_eq!(Color::RED.lighten(0.25), Color::rgb(255, 128, 128));
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s, l + amount)
+    }
+
+    /// Decrease the HSL lightness by `amount` (`0.0` to `1.0`)
+    pub fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Increase the HSL saturation by `amount` (`0.0` to `1.0`)
+    pub fn saturate(&self, amount: f32) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s + amount, l)
+    }
+
+    /// Decrease the HSL saturation by `amount` (`0.0` to `1.0`)
+    pub fn desaturate(&self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Return the inverted color, every channel subtracted from 255
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::rgb(255, 136, 0).invert(), Color::rgb(0, 119, 255));
+    /// ```
+    pub fn invert(&self) -> Color {
+        Color::rgb(255 - self.r, 255 - self.g, 255 - self.b)
+    }
+
+    /// Return the gray with the same luminance as this color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// // Green looks much brighter than blue
+    /// assert!(Color::LIME.grayscale().r > Color::BLUE.grayscale().r);
+    /// ```
+    pub fn grayscale(&self) -> Color {
+        let gray = from_linear(self.luminance());
+        Color::rgb(gray, gray, gray)
+    }
+
+    /// Return how the color looks with the given color vision deficiency
+    ///
+    /// Uses the Machado, Oliveira and Fernandes (2009) model at full severity, so
+    /// output colors can be checked to stay distinguishable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorBlindness};
+    ///
+    /// let red = Color::rgb(200, 50, 50).simulate(ColorBlindness::Deuteranopia);
+    /// let green = Color::rgb(110, 110, 50).simulate(ColorBlindness::Deuteranopia);
+    /// assert!(red.distance(green) < 0.1);
+    /// ```
+    pub fn simulate(&self, kind: ColorBlindness) -> Color {
+        let matrix = match kind {
+            ColorBlindness::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            ColorBlindness::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            ColorBlindness::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+            ColorBlindness::Achromatopsia => return self.grayscale(),
+        };
+
+        let rgb = [to_linear(self.r), to_linear(self.g), to_linear(self.b)];
+        let channel = |row: [f32; 3]| from_linear((row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]).clamp(0.0, 1.0));
+        Color::rgb(channel(matrix[0]), channel(matrix[1]), channel(matrix[2]))
+    }
+
+    /// Return the complementary color, the opposite hue on the color wheel
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::RED.complement(), Color::CYAN);
+    /// ```
+    pub fn complement(&self) -> Color {
+        self.rotate_hue(180.0)
+    }
+
+    /// Return the color and the two colors 120 degrees away on the color wheel
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::RED.triad(), [Color::RED, Color::LIME, Color::BLUE]);
+    /// ```
+    pub fn triad(&self) -> [Color; 3] {
+        [*self, self.rotate_hue(120.0), self.rotate_hue(240.0)]
+    }
+
+    /// Return `n` neighbouring colors 30 degrees apart, centered on this color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrintExt};
+    ///
+    /// for color in Color::rgb(30, 144, 255).analogous(5) {
+    ///     print!("{}", "#".fg(color));
+    /// }
+    /// ```
+    pub fn analogous(&self, n: usize) -> Vec<Color> {
+        let first = -30.0 * (n.saturating_sub(1)) as f32 / 2.0;
+        (0..n).map(|i| self.rotate_hue(first + 30.0 * i as f32)).collect()
+    }
+
+    /// Rotate the hue by `degrees` on the color wheel, keeping the saturation and lightness
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrintExt};
+    ///
+    /// assert_eq!(Color::RED.rotate_hue(120.0), Color::LIME);
+    ///
+    /// // A rainbow from a single base color
+    /// for step in 0..12 {
+    ///     print!("{}", "#".fg(Color::RED.rotate_hue(step as f32 * 30.0)));
+    /// }
+    /// ```
+    pub fn rotate_hue(&self, degrees: f32) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h + degrees, s, l)
+    }
+
+    /// Shift the color toward warm amber (positive `shift`) or cool blue (negative `shift`) tones
+    ///
+    /// The shift moves the color along the blue to yellow axis of OKLab, so the perceived
+    /// lightness is kept and a whole palette can be turned into a day or night variant.
+    ///
+    /// # Params
+    /// - `shift`: From `-1.0` (coolest) to `1.0` (warmest), `0.0` returns the same color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// let base = Color::rgb(120, 140, 160);
+    /// let night = base.temperature(0.4);
+    /// let day = base.temperature(-0.2);
+    /// assert!(night.r > base.r && night.b < base.b);
+    /// assert!(day.r < base.r && day.b > base.b);
+    /// ```
+    pub fn temperature(&self, shift: f32) -> Color {
+        let shift = shift.clamp(-1.0, 1.0);
+        if shift == 0.0 {
+            return *self;
+        }
+        let (l, a, b) = self.to_oklab();
+        Color::from_oklab(l, a + 0.03 * shift, b + 0.12 * shift)
+    }
+
+    /// Return the WCAG relative luminance, from `0.0` (black) to `1.0` (white)
+    pub fn luminance(&self) -> f32 {
+        0.2126 * to_linear(self.r) + 0.7152 * to_linear(self.g) + 0.0722 * to_linear(self.b)
+    }
+
+    /// Return the WCAG contrast ratio with `other`, from `1.0` (same luminance) to `21.0`
+    ///
+    /// WCAG recommends at least `4.5` for normal text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert!((Color::BLACK.contrast_ratio(Color::WHITE) - 21.0).abs() < 0.01);
+    /// assert!(Color::rgb(30, 144, 255).contrast_ratio(Color::WHITE) < 4.5);
+    /// ```
+    pub fn contrast_ratio(&self, other: Color) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Return black or white, whichever is more readable as text on top of this color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::YELLOW.readable_fg(), Color::BLACK);
+    /// assert_eq!(Color::rgb(0, 0, 128).readable_fg(), Color::WHITE);
+    /// ```
+    pub fn readable_fg(&self) -> Color {
+        if self.contrast_ratio(Color::BLACK) >= self.contrast_ratio(Color::WHITE) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+
+    /// Create a random color
+    pub fn random() -> Color {
+        Color::random_from(&mut rand::thread_rng())
+    }
+
+    /// Create a random color, the same `seed` always gives the same color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::random_with_seed(7), Color::random_with_seed(7));
+    /// ```
+    pub fn random_with_seed(seed: u64) -> Color {
+        Color::random_from(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Create a random color with a moderate saturation and lightness, readable on dark and light backgrounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, ColorPrintExt};
+    ///
+    /// for thread in 0..4 {
+    ///     println!("{}", format!("thread {}", thread).as_str().fg(Color::random_pleasant()));
+    /// }
+    /// ```
+    pub fn random_pleasant() -> Color {
+        Color::random_pleasant_from(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Color::random_pleasant`], the same `seed` always gives the same color
+    pub fn random_pleasant_with_seed(seed: u64) -> Color {
+        Color::random_pleasant_from(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Create a random color with the given generator
+    fn random_from(rng: &mut impl Rng) -> Color {
+        Color::rgb(rng.gen(), rng.gen(), rng.gen())
+    }
+
+    /// Create a random pleasant color with the given generator
+    fn random_pleasant_from(rng: &mut impl Rng) -> Color {
+        Color::from_hsl(rng.gen_range(0.0..360.0), rng.gen_range(0.5..0.8), rng.gen_range(0.5..0.7))
+    }
+
+    /// Create a color from its index in the xterm 256 color palette
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::from_ansi256(196), Color::RED);
+    /// assert_eq!(Color::from_ansi256(196).to_ansi256(), 196);
+    /// ```
+    pub fn from_ansi256(index: u8) -> Color {
+        crate::colors::xterm::PALETTE[index as usize]
+    }
+
+    /// Return the index of the nearest color in the xterm 256 color palette
+    ///
+    /// Only the 6x6x6 color cube (16 to 231) and the grayscale ramp (232 to 255) are
+    /// considered, the first 16 colors depend on the terminal theme. Colors of those
+    /// ranges round-trip with [`Color::from_ansi256`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::rgb(255, 0, 0).to_ansi256(), 196);
+    /// assert_eq!(Color::rgb(128, 128, 128).to_ansi256(), 244);
+    /// ```
+    pub fn to_ansi256(&self) -> u8 {
+        const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        let nearest_level = |channel: u8| {
+            (0..LEVELS.len())
+                .min_by_key(|&i| (LEVELS[i] as i16 - channel as i16).abs())
+                .unwrap() as u8
+        };
+
+        let (r, g, b) = (nearest_level(self.r), nearest_level(self.g), nearest_level(self.b));
+        let cube = Color::rgb(LEVELS[r as usize], LEVELS[g as usize], LEVELS[b as usize]);
+
+        let average = (self.r as u16 + self.g as u16 + self.b as u16) / 3;
+        let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray_level = 8 + gray_index * 10;
+        let gray = Color::rgb(gray_level, gray_level, gray_level);
+
+        if self.distance(gray) < self.distance(cube) {
+            232 + gray_index
+        } else {
+            16 + 36 * r + 6 * g + b
+        }
+    }
+
+    /// Return the index (0 to 15) of the nearest of the 16 classic ANSI colors
+    ///
+    /// 0 to 7 are the standard colors and 8 to 15 their bright variants, using
+    /// the xterm default values as reference.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// assert_eq!(Color::rgb(200, 10, 10).to_ansi16(), 1);
+    /// assert_eq!(Color::rgb(250, 250, 250).to_ansi16(), 15);
+    /// ```
+    pub fn to_ansi16(&self) -> u8 {
+        crate::colors::xterm::PALETTE[..16]
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, color)| self.distance_squared(color))
+            .map(|(i, _)| i as u8)
+            .unwrap()
+    }
+
+    /// Return the perceptual distance (Delta E in OKLab) between two colors
+    ///
+    /// `0.0` for identical colors and about `1.0` between black and white; differences
+    /// below `0.02` are hard to notice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// let orange = Color::rgb(255, 136, 0);
+    /// assert!(orange.distance(Color::rgb(255, 140, 0)) < orange.distance(Color::rgb(255, 100, 0)));
+    /// ```
+    pub fn distance(&self, other: Color) -> f32 {
+        let (l1, a1, b1) = self.to_oklab();
+        let (l2, a2, b2) = other.to_oklab();
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// Squared euclidean distance between two colors in RGB
+    ///
+    /// Used for the 16 classic colors, where keeping the hue matters more than the lightness.
+    fn distance_squared(&self, other: &Color) -> u32 {
+        let diff = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+        diff(self.r, other.r) + diff(self.g, other.g) + diff(self.b, other.b)
+    }
+
+    /// Return the index of the perceptually nearest color of `palette`, `None` if it is empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{colors, Color};
+    ///
+    /// let palette = [colors::NAVY, colors::TEAL, colors::CRIMSON];
+    /// assert_eq!(Color::rgb(200, 30, 60).nearest(&palette), Some(2));
+    /// ```
+    pub fn nearest(&self, palette: &[Color]) -> Option<usize> {
+        palette
+            .iter()
+            .map(|color| self.distance(*color))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(i, _)| i)
+    }
+
+    /// Hue in degrees and the largest and smallest channels from `0.0` to `1.0`
+    fn hue_max_min(&self) -> (f32, f32, f32) {
+        let (r, g, b) = (self.r as f32 / 255.0, self.g as f32 / 255.0, self.b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (hue, max, min)
+    }
+}
+
+/// The color space used to interpolate between two colors
+///
+/// # Variants
+/// - `Rgb`: Interpolate every RGB channel, red to green goes through brown
+/// - `Oklab`: Interpolate in the perceptual OKLab space, giving smoother midpoints
+/// - `Hsl`: Interpolate the hue along the shorter way around the color wheel, red to blue goes
+///   through magenta and red to green through yellow, never through gray
+/// - `HslLong`: Interpolate the hue along the longer way around the color wheel, red to green goes
+///   through magenta and blue, and two colors with the same hue go through the whole wheel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Interpolation {
+    /// Interpolate every RGB channel
+    #[default]
+    Rgb,
+    /// Interpolate in the perceptual OKLab space
+    Oklab,
+    /// Interpolate the hue, saturation and lightness
+    Hsl,
+    /// Interpolate the hue the long way around, the saturation and the lightness
+    HslLong,
+}
+
+impl Interpolation {
+    /// Mix two colors, `t` goes from `0.0` (`start`) to `1.0` (`end`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Interpolation};
+    ///
+    /// assert_eq!(Interpolation::Rgb.mix(Color::BLACK, Color::WHITE, 0.5), Color::rgb(128, 128, 128));
+    /// ```
+    pub fn mix(&self, start: Color, end: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+        match self {
+            Interpolation::Rgb => {
+                let channel = |a: u8, b: u8| lerp(a as f32, b as f32).round() as u8;
+                Color::rgb(channel(start.r, end.r), channel(start.g, end.g), channel(start.b, end.b))
+            }
+            Interpolation::Oklab => {
+                let (l1, a1, b1) = start.to_oklab();
+                let (l2, a2, b2) = end.to_oklab();
+                Color::from_oklab(lerp(l1, l2), lerp(a1, a2), lerp(b1, b2))
+            }
+            Interpolation::Hsl | Interpolation::HslLong => {
+                let (mut h1, s1, l1) = start.to_hsl();
+                let (mut h2, s2, l2) = end.to_hsl();
+                // Grays have no hue, they take the one of the other color
+                if s1 == 0.0 {
+                    h1 = h2;
+                }
+                if s2 == 0.0 {
+                    h2 = h1;
+                }
+                let mut delta = (h2 - h1 + 540.0) % 360.0 - 180.0;
+                if *self == Interpolation::HslLong {
+                    delta = if delta > 0.0 { delta - 360.0 } else { delta + 360.0 };
+                }
+                Color::from_hsl(h1 + delta * t, lerp(s1, s2), lerp(l1, l2))
+            }
+        }
+    }
+}
+
+/// A color vision deficiency, see [`Color::simulate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorBlindness {
+    /// No red cones, red and green are confused
+    Protanopia,
+    /// No green cones, red and green are confused (the most common one)
+    Deuteranopia,
+    /// No blue cones, blue and yellow are confused
+    Tritanopia,
+    /// No color vision at all
+    Achromatopsia,
+}
+
+/// Convert a sRGB channel into linear light
+fn to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+/// Convert linear light into a sRGB channel
+fn from_linear(value: f32) -> u8 {
+    let c = if value <= 0.0031308 { value * 12.92 } else { 1.055 * value.powf(1.0 / 2.4) - 0.055 };
+    (c * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// Build a color from a hue, its chroma and the amount added to every channel
+fn from_hue_chroma(h: f32, chroma: f32, m: f32) -> Color {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let channel = |value: f32| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    Color::rgb(channel(r), channel(g), channel(b))
+}
+
+impl std::str::FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Parse a hex color (`#ff8800`), a CSS functional color or a CSS color name (`orange`)
+    ///
+    /// Hex colors need their `#`, otherwise a name like `fed` could be taken for a color.
+    /// The functional colors are `rgb()`, `rgba()`, `hsl()` and `hsla()`, with comma or space
+    /// separated arguments and percentages. `Color` has no alpha channel, so the alpha is
+    /// ignored; use [`Color::over`] to composite it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Color;
+    ///
+    /// let orange = Color::rgb(255, 136, 0);
+    /// assert_eq!("#ff8800".parse(), Ok(orange));
+    /// assert_eq!("rgb(255, 136, 0)".parse(), Ok(orange));
+    /// assert_eq!("rgba(255 136 0 / 50%)".parse(), Ok(orange));
+    /// assert_eq!("hsl(120, 100%, 50%)".parse(), Ok(Color::LIME));
+    /// assert_eq!("orange".parse(), Ok(Color::rgb(255, 165, 0)));
+    /// ```
+    fn from_str(s: &str) -> Result<Color, ColorParseError> {
+        let s = s.trim();
+
+        if s.starts_with('#') {
+            return Color::from_hex(s);
+        }
+
+        if let Some((function, args)) = s.strip_suffix(')').and_then(|rest| rest.split_once('(')) {
+            return parse_functional(function.trim(), args)
+                .map_err(|reason| ColorParseError::InvalidFunction { input: s.to_string(), reason });
+        }
+
+        crate::colors::by_name(s).ok_or_else(|| ColorParseError::UnknownColor(s.to_string()))
+    }
+}
+
+/// Parse the arguments of a CSS functional color like `rgb(...)` or `hsl(...)`
+fn parse_functional(function: &str, args: &str) -> Result<Color, String> {
+    // Both `r, g, b, a` and `r g b / a` are valid
+    let args: Vec<&str> = args
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|arg| !arg.is_empty())
+        .collect();
+
+    let (function, channels) = match (function.to_ascii_lowercase().as_str(), &args[..]) {
+        ("rgb" | "rgba", [a, b, c] | [a, b, c, _]) => ("rgb", [*a, *b, *c]),
+        ("hsl" | "hsla", [a, b, c] | [a, b, c, _]) => ("hsl", [*a, *b, *c]),
+        ("rgb" | "rgba" | "hsl" | "hsla", _) => return Err(format!("{}() takes 3 or 4 arguments", function)),
+        _ => return Err(format!("unknown color function {}()", function)),
+    };
+
+    if function == "rgb" {
+        let channel = |arg: &str| match arg.strip_suffix('%') {
+            Some(percent) => percent
+                .parse::<f32>()
+                .ok()
+                .filter(|percent| (0.0..=100.0).contains(percent))
+                .map(|percent| (percent * 2.55).round() as u8),
+            None => arg.parse::<u8>().ok(),
+        };
+        match (channel(channels[0]), channel(channels[1]), channel(channels[2])) {
+            (Some(r), Some(g), Some(b)) => Ok(Color::rgb(r, g, b)),
+            _ => Err("channels must be numbers from 0 to 255 or percentages".to_string()),
+        }
+    } else {
+        let hue = channels[0].strip_suffix("deg").unwrap_or(channels[0]).parse::<f32>();
+        let percent = |arg: &str| {
+            arg.strip_suffix('%')
+                .and_then(|percent| percent.parse::<f32>().ok())
+                .filter(|percent| (0.0..=100.0).contains(percent))
+                .map(|percent| percent / 100.0)
+        };
+        match (hue, percent(channels[1]), percent(channels[2])) {
+            (Ok(h), Some(s), Some(l)) => Ok(Color::from_hsl(h, s, l)),
+            _ => Err("hue must be a number and saturation and lightness percentages".to_string()),
+        }
+    }
+}
+
+/// Serialized as a hex string, `#rrggbb`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+/// Deserialized from any string accepted by `FromStr` (hex, `rgb()` or a name) or a `[r, g, b]` array
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        struct ColorVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a hex color, a color name or a [r, g, b] array")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Color, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+                let mut channel = |i| seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(i, &self));
+                let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+                Ok(Color::rgb(r, g, b))
+            }
+        }
+
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+/// Mix two colors in RGB, `t` goes from `0.0` (`a`) to `1.0` (`b`)
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{mix, Color};
+///
+/// assert_eq!(mix(Color::RED, Color::BLUE, 0.5), Color::rgb(128, 0, 128));
+/// assert_eq!(mix((0, 0, 0), (200, 100, 50), 0.25), Color::rgb(50, 25, 13));
+/// ```
+pub fn mix(a: impl Into<Color>, b: impl Into<Color>, t: f32) -> Color {
+    Interpolation::Rgb.mix(a.into(), b.into(), t)
+}
+
+/// Add the channels, saturating at 255
+impl std::ops::Add for Color {
+    type Output = Color;
+
+    fn add(self, other: Color) -> Color {
+        Color::rgb(self.r.saturating_add(other.r), self.g.saturating_add(other.g), self.b.saturating_add(other.b))
+    }
+}
+
+/// Scale the channels, clamping them between 0 and 255
+impl std::ops::Mul<f32> for Color {
+    type Output = Color;
+
+    fn mul(self, factor: f32) -> Color {
+        let scale = |channel: u8| (channel as f32 * factor).round().clamp(0.0, 255.0) as u8;
+        Color::rgb(scale(self.r), scale(self.g), scale(self.b))
+    }
+}
+
+/// Formats the color as its hex string, see [`Color::to_hex`]
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Color {
+        Color::rgb(r, g, b)
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(color: Color) -> (u8, u8, u8) {
+        (color.r, color.g, color.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_hex() {
+        assert_eq!(Color::from_hex("#1e90ff"), Ok(Color::rgb(30, 144, 255)));
+        assert_eq!(Color::from_hex("1E90FF"), Ok(Color::rgb(30, 144, 255)));
+        assert_eq!(Color::from_hex("#fff"), Ok(Color::WHITE));
+        assert!(Color::from_hex("#12345").is_err());
+        assert!(Color::from_hex("#12345g").is_err());
+        assert!(Color::from_hex("").is_err());
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        let color: Color = (1, 2, 3).into();
+        assert_eq!(color, Color::rgb(1, 2, 3));
+        assert_eq!(<(u8, u8, u8)>::from(color), (1, 2, 3));
+    }
+
+    #[test]
+    fn test_hsl() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::BLUE);
+        assert_eq!(Color::from_hsl(-120.0, 1.0, 0.5), Color::BLUE);
+        assert_eq!(Color::from_hsl(0.0, 0.0, 1.0), Color::WHITE);
+        assert_eq!(Color::RED.to_hsl(), (0.0, 1.0, 0.5));
+        assert_eq!(Color::GRAY.to_hsl().1, 0.0);
+
+        let dodger_blue = Color::rgb(30, 144, 255);
+        let (h, s, l) = dodger_blue.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), dodger_blue);
+    }
+
+    #[test]
+    fn test_hsv() {
+        assert_eq!(Color::from_hsv(60.0, 1.0, 1.0), Color::YELLOW);
+        assert_eq!(Color::from_hsv(300.0, 1.0, 1.0), Color::MAGENTA);
+        assert_eq!(Color::from_hsv(0.0, 0.0, 0.0), Color::BLACK);
+        assert_eq!(Color::CYAN.to_hsv(), (180.0, 1.0, 1.0));
+        assert_eq!(Color::BLACK.to_hsv(), (0.0, 0.0, 0.0));
+
+        let orange = Color::rgb(255, 136, 0);
+        let (h, s, v) = orange.to_hsv();
+        assert_eq!(Color::from_hsv(h, s, v), orange);
+    }
+
+    #[test]
+    fn test_oklab() {
+        let (l, a, b) = Color::WHITE.to_oklab();
+        assert!((l - 1.0).abs() < 1e-3 && a.abs() < 1e-3 && b.abs() < 1e-3);
+
+        for color in [Color::RED, Color::rgb(30, 144, 255), Color::rgb(12, 200, 77)] {
+            let (l, a, b) = color.to_oklab();
+            assert_eq!(Color::from_oklab(l, a, b), color);
+        }
+    }
+
+    #[test]
+    fn test_interpolation_mix() {
+        assert_eq!(Interpolation::Rgb.mix(Color::RED, Color::LIME, 0.5), Color::rgb(128, 128, 0));
+
+        // OKLab keeps the midpoint of red and green bright instead of going through brown
+        let midpoint = Interpolation::Oklab.mix(Color::RED, Color::LIME, 0.5);
+        assert!(midpoint.to_oklab().0 > Color::rgb(128, 128, 0).to_oklab().0);
+        assert_eq!(Interpolation::Oklab.mix(Color::RED, Color::LIME, 0.0), Color::RED);
+        assert_eq!(Interpolation::Oklab.mix(Color::RED, Color::LIME, 1.0), Color::LIME);
+    }
+
+    #[test]
+    fn test_hsl_interpolation() {
+        assert_eq!(Interpolation::Hsl.mix(Color::RED, Color::LIME, 0.5), Color::rgb(255, 255, 0));
+        assert_eq!(Interpolation::Hsl.mix(Color::RED, Color::BLUE, 0.5), Color::rgb(255, 0, 255));
+        assert_eq!(Interpolation::Hsl.mix(Color::BLUE, Color::RED, 0.0), Color::BLUE);
+        assert_eq!(Interpolation::Hsl.mix(Color::BLUE, Color::RED, 1.0), Color::RED);
+        // The shorter way from magenta-red to orange crosses 0 degrees
+        let (rose, orange) = (Color::from_hsl(340.0, 1.0, 0.5), Color::from_hsl(20.0, 1.0, 0.5));
+        assert_eq!(Interpolation::Hsl.mix(rose, orange, 0.5), Color::RED);
+        // A gray keeps the hue of the other color
+        assert_eq!(Interpolation::Hsl.mix(Color::WHITE, Color::RED, 0.5), Color::from_hsl(0.0, 0.5, 0.75));
+    }
+
+    #[test]
+    fn test_hsl_long_interpolation() {
+        // Red to green, yellow the short way and blue the long way
+        let green = Color::from_hsl(120.0, 1.0, 0.5);
+        assert_eq!(Interpolation::Hsl.mix(Color::RED, green, 0.5), Color::rgb(255, 255, 0));
+        assert_eq!(Interpolation::HslLong.mix(Color::RED, green, 0.5), Color::BLUE);
+        assert_eq!(Interpolation::HslLong.mix(green, Color::RED, 0.5), Color::BLUE);
+        assert_eq!(Interpolation::HslLong.mix(Color::RED, green, 1.0), green);
+
+        // Across 0 degrees the long way goes through cyan instead of red
+        let (rose, orange) = (Color::from_hsl(340.0, 1.0, 0.5), Color::from_hsl(20.0, 1.0, 0.5));
+        assert_eq!(Interpolation::HslLong.mix(rose, orange, 0.5), Color::CYAN);
+        // The same hue goes through the whole wheel
+        assert_eq!(Interpolation::Hsl.mix(Color::RED, Color::RED, 0.5), Color::RED);
+        assert_eq!(Interpolation::HslLong.mix(Color::RED, Color::RED, 0.5), Color::CYAN);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("#ff8800".parse(), Ok(Color::rgb(255, 136, 0)));
+        assert_eq!(" rgb( 255 ,136, 0 ) ".parse(), Ok(Color::rgb(255, 136, 0)));
+        assert_eq!("Dodger Blue".parse(), Ok(Color::rgb(30, 144, 255)));
+        assert!("rgb(256, 0, 0)".parse::<Color>().is_err());
+        assert!("rgb(1, 2)".parse::<Color>().is_err());
+        assert!("#ggg".parse::<Color>().is_err());
+        assert!("not a color".parse::<Color>().is_err());
+        for word in ["bad", "fed", "decade", "ff8800"] {
+            assert_eq!(word.parse::<Color>(), Err(ColorParseError::UnknownColor(word.to_string())));
+        }
+    }
+
+    #[test]
+    fn test_to_ansi256() {
+        assert_eq!(Color::BLACK.to_ansi256(), 16);
+        assert_eq!(Color::WHITE.to_ansi256(), 231);
+        assert_eq!(Color::BLUE.to_ansi256(), 21);
+        assert_eq!(Color::rgb(255, 136, 0).to_ansi256(), 208);
+        assert_eq!(Color::rgb(8, 8, 8).to_ansi256(), 232);
+        assert_eq!(Color::rgb(238, 238, 238).to_ansi256(), 255);
+        assert_eq!(Color::rgb(100, 100, 100).to_ansi256(), 241);
+    }
+
+    #[test]
+    fn test_to_ansi16() {
+        assert_eq!(Color::BLACK.to_ansi16(), 0);
+        assert_eq!(Color::RED.to_ansi16(), 9);
+        assert_eq!(Color::rgb(0, 128, 0).to_ansi16(), 2);
+        assert_eq!(Color::rgb(30, 30, 200).to_ansi16(), 4);
+        assert_eq!(Color::GRAY.to_ansi16(), 8);
+        assert_eq!(Color::WHITE.to_ansi16(), 15);
+    }
+
+    #[test]
+    fn test_blend_and_over() {
+        assert_eq!(Color::RED.blend(Color::BLUE, 0.0), Color::RED);
+        assert_eq!(Color::RED.blend(Color::BLUE, 1.0), Color::BLUE);
+        assert_eq!(Color::RED.blend(Color::BLUE, 0.5), Color::rgb(128, 0, 128));
+        assert_eq!(Color::WHITE.over(Color::BLACK, 0.2), Color::rgb(51, 51, 51));
+        assert_eq!(Color::WHITE.over(Color::BLACK, 2.0), Color::WHITE);
+    }
+
+    #[test]
+    fn test_lightness_and_saturation() {
+        assert_eq!(Color::RED.lighten(1.0), Color::WHITE);
+        assert_eq!(Color::RED.darken(0.25), Color::rgb(128, 0, 0));
+        assert_eq!(Color::RED.darken(1.0), Color::BLACK);
+        assert_eq!(Color::RED.desaturate(1.0), Color::rgb(128, 128, 128));
+        assert_eq!(Color::rgb(191, 64, 64).saturate(0.5), Color::RED);
+        assert_eq!(Color::RED.lighten(0.2).darken(0.2), Color::RED);
+    }
+
+    #[test]
+    fn test_harmonies() {
+        assert_eq!(Color::BLUE.complement(), Color::YELLOW);
+        assert_eq!(Color::GRAY.complement(), Color::GRAY);
+        assert_eq!(Color::LIME.triad(), [Color::LIME, Color::BLUE, Color::RED]);
+        assert_eq!(Color::RED.analogous(3), [Color::rgb(255, 0, 128), Color::RED, Color::rgb(255, 128, 0)]);
+        assert_eq!(Color::RED.analogous(2), [Color::rgb(255, 0, 64), Color::rgb(255, 64, 0)]);
+        assert!(Color::RED.analogous(0).is_empty());
+    }
+
+    #[test]
+    fn test_contrast() {
+        assert_eq!(Color::WHITE.luminance(), 1.0);
+        assert_eq!(Color::BLACK.luminance(), 0.0);
+        assert_eq!(Color::RED.contrast_ratio(Color::RED), 1.0);
+        assert_eq!(Color::WHITE.contrast_ratio(Color::BLACK), Color::BLACK.contrast_ratio(Color::WHITE));
+        assert!((Color::RED.contrast_ratio(Color::WHITE) - 4.0).abs() < 0.01);
+
+        assert_eq!(Color::WHITE.readable_fg(), Color::BLACK);
+        assert_eq!(Color::BLACK.readable_fg(), Color::WHITE);
+        assert_eq!(Color::rgb(0, 255, 0).readable_fg(), Color::BLACK);
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(Color::RED.distance(Color::RED), 0.0);
+        assert!((Color::BLACK.distance(Color::WHITE) - 1.0).abs() < 0.01);
+        assert_eq!(Color::RED.distance(Color::BLUE), Color::BLUE.distance(Color::RED));
+
+        assert_eq!(Color::RED.nearest(&[]), None);
+        assert_eq!(Color::rgb(250, 10, 10).nearest(&[Color::BLUE, Color::RED, Color::LIME]), Some(1));
+    }
+
+    #[test]
+    fn test_random() {
+        assert_eq!(Color::random_with_seed(1), Color::random_with_seed(1));
+        assert_ne!(Color::random_with_seed(1), Color::random_with_seed(2));
+
+        for seed in 0..50 {
+            let (_, s, l) = Color::random_pleasant_with_seed(seed).to_hsl();
+            assert!((0.48..=0.82).contains(&s), "saturation {}", s);
+            assert!((0.48..=0.72).contains(&l), "lightness {}", l);
+        }
+    }
+
+    #[test]
+    fn test_ansi256_round_trip() {
+        assert_eq!(Color::from_ansi256(0), Color::BLACK);
+        assert_eq!(Color::from_ansi256(231), Color::WHITE);
+        assert_eq!(Color::from_ansi256(244), Color::rgb(128, 128, 128));
+
+        for index in 16..=255 {
+            assert_eq!(Color::from_ansi256(index).to_ansi256(), index);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let color = Color::rgb(255, 136, 0);
+        assert_eq!(serde_json::to_string(&color).unwrap(), "\"#ff8800\"");
+
+        assert_eq!(serde_json::from_str::<Color>("\"#ff8800\"").unwrap(), color);
+        assert_eq!(serde_json::from_str::<Color>("[255, 136, 0]").unwrap(), color);
+        assert_eq!(serde_json::from_str::<Color>("\"teal\"").unwrap(), Color::rgb(0, 128, 128));
+        assert!(serde_json::from_str::<Color>("[255, 136]").is_err());
+        assert!(serde_json::from_str::<Color>("[256, 0, 0]").is_err());
+        assert!(serde_json::from_str::<Color>("\"not a color\"").is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Color::RED + Color::BLUE, Color::MAGENTA);
+        assert_eq!(Color::rgb(200, 10, 0) + Color::rgb(100, 10, 0), Color::rgb(255, 20, 0));
+        assert_eq!(Color::rgb(200, 100, 50) * 0.5, Color::rgb(100, 50, 25));
+        assert_eq!(Color::rgb(200, 100, 50) * 2.0, Color::rgb(255, 200, 100));
+        assert_eq!(Color::WHITE * -1.0, Color::BLACK);
+        assert_eq!(Color::RED * 0.5 + Color::BLUE * 0.5, mix(Color::RED, Color::BLUE, 0.5));
+    }
+
+    #[test]
+    fn test_invert_and_grayscale() {
+        assert_eq!(Color::BLACK.invert(), Color::WHITE);
+        assert_eq!(Color::RED.invert(), Color::CYAN);
+        assert_eq!(Color::rgb(12, 34, 56).invert().invert(), Color::rgb(12, 34, 56));
+
+        assert_eq!(Color::WHITE.grayscale(), Color::WHITE);
+        assert_eq!(Color::rgb(77, 77, 77).grayscale(), Color::rgb(77, 77, 77));
+        assert_eq!(Color::RED.grayscale(), Color::rgb(127, 127, 127));
+        assert_eq!(Color::BLUE.grayscale(), Color::rgb(76, 76, 76));
+    }
+
+    #[test]
+    fn test_rotate_hue() {
+        assert_eq!(Color::RED.rotate_hue(0.0), Color::RED);
+        assert_eq!(Color::RED.rotate_hue(360.0), Color::RED);
+        assert_eq!(Color::RED.rotate_hue(-120.0), Color::BLUE);
+        assert_eq!(Color::GRAY.rotate_hue(90.0), Color::GRAY);
+    }
+
+    #[test]
+    fn test_const_hex() {
+        const ORANGE: Color = Color::const_hex("#FF8800");
+        const SHORT: Color = Color::const_hex("f80");
+
+        assert_eq!(ORANGE, Color::rgb(255, 136, 0));
+        assert_eq!(SHORT, ORANGE);
+    }
+
+    #[test]
+    #[should_panic(expected = "3 or 6 digits")]
+    fn test_const_hex_invalid_length() {
+        Color::const_hex("#ff88");
+    }
+
+    #[test]
+    fn test_simulate() {
+        for kind in [ColorBlindness::Protanopia, ColorBlindness::Deuteranopia, ColorBlindness::Tritanopia] {
+            assert_eq!(Color::BLACK.simulate(kind), Color::BLACK);
+            assert!(Color::WHITE.simulate(kind).distance(Color::WHITE) < 0.02);
+        }
+        assert_eq!(Color::RED.simulate(ColorBlindness::Achromatopsia), Color::RED.grayscale());
+
+        // Red and green become hard to tell apart for red-green deficiencies but not for tritanopia
+        let (red, green) = (Color::rgb(213, 94, 0), Color::rgb(0, 158, 115));
+        let gap = |kind| red.simulate(kind).distance(green.simulate(kind));
+        assert!(gap(ColorBlindness::Deuteranopia) < gap(ColorBlindness::Tritanopia));
+    }
+
+    #[test]
+    fn test_from_str_functional() {
+        let orange = Color::rgb(255, 136, 0);
+        assert_eq!("RGB(255,136,0)".parse(), Ok(orange));
+        assert_eq!("rgba(255, 136, 0, 0.5)".parse(), Ok(orange));
+        assert_eq!("rgb(100%, 0%, 50%)".parse(), Ok(Color::rgb(255, 0, 128)));
+        assert_eq!("hsl(240deg 100% 50%)".parse(), Ok(Color::BLUE));
+        assert_eq!("hsla(0, 100%, 50%, 1)".parse(), Ok(Color::RED));
+
+        assert!("rgb(255, 136)".parse::<Color>().is_err());
+        assert!("rgb(101%, 0, 0)".parse::<Color>().is_err());
+        assert!("hsl(120, 50, 50)".parse::<Color>().is_err());
+        assert!("lab(50, 0, 0)".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(Color::BLACK.to_hex(), "#000000");
+        assert_eq!(format!("{}", Color::rgb(1, 171, 255)), "#01abff");
+        for color in [Color::ORANGE, Color::rgb(18, 52, 86)] {
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+    }
+
+    #[test]
+    fn test_temperature() {
+        assert_eq!(Color::ORANGE.temperature(0.0), Color::ORANGE);
+        assert_eq!(Color::GRAY.temperature(2.0), Color::GRAY.temperature(1.0));
+
+        let warm = Color::GRAY.temperature(0.5);
+        let cool = Color::GRAY.temperature(-0.5);
+        assert!(warm.r > warm.g && warm.g > warm.b);
+        assert!(cool.b > cool.g && cool.g > cool.r);
+        assert!((warm.to_oklab().0 - Color::GRAY.to_oklab().0).abs() < 0.02);
+    }
+}
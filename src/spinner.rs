@@ -0,0 +1,176 @@
+// spinner.rs
+use crate::table::paint;
+use crate::Style;
+use std::fmt::Display;
+
+/// The frames of a spinner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpinnerPreset {
+    /// Braille dots turning around, `⠋ ⠙ ⠹ ⠸ ...`
+    Dots,
+    /// A turning line, `- \ | /`, printable in every terminal
+    Line,
+    /// A quarter circle turning around, `◜ ◠ ◝ ...`
+    Arc,
+    /// A dot bouncing up and down, `⠁ ⠂ ⠄ ⠂`
+    Bounce,
+    /// The phases of the moon
+    Moon,
+}
+
+impl SpinnerPreset {
+    /// Every preset
+    pub const ALL: [SpinnerPreset; 5] =
+        [SpinnerPreset::Dots, SpinnerPreset::Line, SpinnerPreset::Arc, SpinnerPreset::Bounce, SpinnerPreset::Moon];
+
+    /// Return the frames of the preset, in order
+    pub fn frames(&self) -> &'static [&'static str] {
+        match self {
+            SpinnerPreset::Dots => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            SpinnerPreset::Line => &["-", "\\", "|", "/"],
+            SpinnerPreset::Arc => &["◜", "◠", "◝", "◞", "◡", "◟"],
+            SpinnerPreset::Bounce => &["⠁", "⠂", "⠄", "⠂"],
+            SpinnerPreset::Moon => &["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"],
+        }
+    }
+}
+
+/// A spinner for long running operations, followed by a message
+///
+/// Every call to [`Spinner::tick`] returns the next frame, print it over the previous one with `\r`.
+///
+/// # Example
+///
+/// ```no_run
+/// use polychrome::{Spinner, SpinnerPreset, Style};
+/// use std::io::Write;
+///
+/// let mut spinner = Spinner::new(SpinnerPreset::Dots).style(Style::new().color(0, 200, 255)).message("Compiling");
+/// for _ in 0..50 {
+///     print!("\r{}", spinner.tick());
+///     std::io::stdout().flush().unwrap();
+///     std::thread::sleep(std::time::Duration::from_millis(80));
+/// }
+/// println!("\rDone!       ");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spinner {
+    frames: Vec<String>,
+    style: Style,
+    message: String,
+    index: usize,
+}
+
+impl Spinner {
+    /// Create an unstyled spinner with the frames of a preset and no message
+    pub fn new(preset: SpinnerPreset) -> Spinner {
+        Spinner::with_frames(preset.frames())
+    }
+
+    /// Create an unstyled spinner with custom frames and no message
+    ///
+    /// # Params
+    /// - `frames`: The frames in order, a spinner without frames prints only its message
+    pub fn with_frames<S: AsRef<str>>(frames: &[S]) -> Spinner {
+        let frames = frames.iter().map(|frame| frame.as_ref().to_string()).collect();
+        Spinner { frames, style: Style::new(), message: String::new(), index: 0 }
+    }
+
+    /// Set the style of the spinner, the message keeps its own style
+    ///
+    /// With a gradient the spinner takes the next color of the gradient at every frame
+    /// instead of a color per character.
+    pub fn style(mut self, style: Style) -> Spinner {
+        self.style = style;
+        self
+    }
+
+    /// Set the message printed after the spinner
+    ///
+    /// # Params
+    /// - `message`: The message, styled text keeps its style
+    pub fn message(mut self, message: impl Display) -> Spinner {
+        self.set_message(message);
+        self
+    }
+
+    /// Replace the message printed after the spinner, e.g. with the current step
+    pub fn set_message(&mut self, message: impl Display) {
+        self.message = message.to_string();
+    }
+
+    /// Return the number of frames before the spinner loops
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Return true if the spinner has no frames
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Return frame number `index` followed by the message
+    pub fn frame(&self, index: usize) -> String {
+        if self.frames.is_empty() {
+            return self.message.clone();
+        }
+
+        let index = index % self.frames.len();
+        let style = match self.style.gradient.as_ref().filter(|gradient| !gradient.is_empty()) {
+            Some(gradient) => {
+                let color = gradient.at(index as f32 / self.frames.len() as f32);
+                Style { gradient: None, ..self.style.clone() }.fg(color)
+            }
+            None => self.style.clone(),
+        };
+        let spinner = paint(&style, &self.frames[index]);
+        if self.message.is_empty() {
+            spinner
+        } else {
+            format!("{} {}", spinner, self.message)
+        }
+    }
+
+    /// Return the current frame and move to the next one
+    pub fn tick(&mut self) -> String {
+        let frame = self.frame(self.index);
+        self.index = self.index.wrapping_add(1);
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spinner() {
+        for preset in SpinnerPreset::ALL {
+            assert!(preset.frames().len() >= 4);
+        }
+
+        let plain = Style::new().mode(crate::ColorMode::TrueColor);
+        let mut spinner = Spinner::new(SpinnerPreset::Line).style(plain).message("Loading");
+        let frames: Vec<String> = (0..5).map(|_| spinner.tick()).collect();
+        assert_eq!(frames, ["- Loading", "\\ Loading", "| Loading", "/ Loading", "- Loading"]);
+        assert_eq!(spinner.frame(6), "| Loading");
+
+        spinner.set_message("Done");
+        assert_eq!(spinner.frame(0), "- Done");
+        assert_eq!(Spinner::with_frames::<&str>(&[]).message("Waiting").frame(3), "Waiting");
+        assert_eq!(Spinner::with_frames(&[".", ".."]).frame(1), "..");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_spinner_style() {
+        let red = Style::new().color(255, 0, 0).mode(crate::ColorMode::TrueColor);
+        let spinner = Spinner::new(SpinnerPreset::Line).style(red).message("go");
+        assert_eq!(spinner.frame(0), "\x1b[38;2;255;0;0m-\x1b[0m go");
+
+        let gradient = Style::new().gradient((255, 0, 0), (0, 0, 255)).mode(crate::ColorMode::TrueColor);
+        let spinner = Spinner::with_frames(&["a", "b"]).style(gradient);
+        assert_eq!(spinner.frame(0), "\x1b[38;2;255;0;0ma\x1b[0m");
+        assert_eq!(spinner.frame(1), "\x1b[38;2;128;0;128mb\x1b[0m");
+    }
+}
@@ -0,0 +1,1394 @@
+// style.rs
+use crate::gradients::Preset;
+use crate::{Color, ColorMode, ColorPrint, Gradient, TermColor, UnderlineStyle};
+use std::fmt::Display;
+use std::borrow::Cow;
+
+/// A text attribute printed with its own SGR code
+///
+/// Terminals ignore the codes they don't support, so an unsupported style prints the plain text.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Style, TextStyle};
+///
+/// let header = Style::new().text_style(TextStyle::Overline).underline(None);
+/// println!("{}", header.apply("Summary"));
+///
+/// let footnote = Style::new().text_style(TextStyle::Superscript);
+/// println!("E = mc{}", footnote.apply("2"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum TextStyle {
+    /// Bold or increased intensity (SGR 1)
+    Bold,
+    /// Faint or decreased intensity (SGR 2)
+    Dim,
+    /// Italic (SGR 3)
+    Italic,
+    /// Slow blink (SGR 5)
+    Blink,
+    /// Rapid blink (SGR 6)
+    RapidBlink,
+    /// Swap the foreground and background colors (SGR 7)
+    Reverse,
+    /// Hidden text (SGR 8)
+    Conceal,
+    /// A line through the text (SGR 9)
+    Strikethrough,
+    /// Proportional spacing (SGR 26)
+    ProportionalSpacing,
+    /// Reveal concealed text (SGR 28)
+    Reveal,
+    /// A line above the text (SGR 53)
+    Overline,
+    /// Superscript (SGR 73)
+    Superscript,
+    /// Subscript (SGR 74)
+    Subscript,
+}
+
+impl TextStyle {
+    /// Every text style ordered by its SGR parameter
+    pub const ALL: [TextStyle; 13] = [
+        TextStyle::Bold,
+        TextStyle::Dim,
+        TextStyle::Italic,
+        TextStyle::Blink,
+        TextStyle::RapidBlink,
+        TextStyle::Reverse,
+        TextStyle::Conceal,
+        TextStyle::Strikethrough,
+        TextStyle::ProportionalSpacing,
+        TextStyle::Reveal,
+        TextStyle::Overline,
+        TextStyle::Superscript,
+        TextStyle::Subscript,
+    ];
+
+    /// Return the SGR parameter enabling the style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::TextStyle;
+    ///
+    /// assert_eq!(TextStyle::Bold.sgr_param(), 1);
+    /// assert_eq!(TextStyle::Subscript.sgr_param(), 74);
+    /// ```
+    pub fn sgr_param(&self) -> u8 {
+        match self {
+            TextStyle::Bold => 1,
+            TextStyle::Dim => 2,
+            TextStyle::Italic => 3,
+            TextStyle::Blink => 5,
+            TextStyle::RapidBlink => 6,
+            TextStyle::Reverse => 7,
+            TextStyle::Conceal => 8,
+            TextStyle::Strikethrough => 9,
+            TextStyle::ProportionalSpacing => 26,
+            TextStyle::Reveal => 28,
+            TextStyle::Overline => 53,
+            TextStyle::Superscript => 73,
+            TextStyle::Subscript => 74,
+        }
+    }
+
+    /// Return the escape code enabling the style, to combine it with other codes by hand
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::TextStyle;
+    ///
+    /// assert_eq!(TextStyle::Bold.sgr_code(), "\x1b[1m");
+    /// println!("{}warning{}", TextStyle::Italic.sgr_code(), "\x1b[0m");
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn sgr_code(&self) -> &'static str {
+        match self {
+            TextStyle::Bold => "\x1b[1m",
+            TextStyle::Dim => "\x1b[2m",
+            TextStyle::Italic => "\x1b[3m",
+            TextStyle::Blink => "\x1b[5m",
+            TextStyle::RapidBlink => "\x1b[6m",
+            TextStyle::Reverse => "\x1b[7m",
+            TextStyle::Conceal => "\x1b[8m",
+            TextStyle::Strikethrough => "\x1b[9m",
+            TextStyle::ProportionalSpacing => "\x1b[26m",
+            TextStyle::Reveal => "\x1b[28m",
+            TextStyle::Overline => "\x1b[53m",
+            TextStyle::Superscript => "\x1b[73m",
+            TextStyle::Subscript => "\x1b[74m",
+        }
+    }
+
+    /// Return the lowercase name of the style, e.g. `rapid_blink`
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextStyle::Bold => "bold",
+            TextStyle::Dim => "dim",
+            TextStyle::Italic => "italic",
+            TextStyle::Blink => "blink",
+            TextStyle::RapidBlink => "rapid_blink",
+            TextStyle::Reverse => "reverse",
+            TextStyle::Conceal => "conceal",
+            TextStyle::Strikethrough => "strikethrough",
+            TextStyle::ProportionalSpacing => "proportional_spacing",
+            TextStyle::Reveal => "reveal",
+            TextStyle::Overline => "overline",
+            TextStyle::Superscript => "superscript",
+            TextStyle::Subscript => "subscript",
+        }
+    }
+
+    /// Return the bit of the style in `Style::text_styles`
+    fn bit(&self) -> u16 {
+        1 << *self as u16
+    }
+}
+
+/// The color, underline and color mode of a text, without the text
+///
+/// A style is built once and applied to many texts, every [`ColorPrint`] keeps its own.
+///
+/// With the `serde` feature a style is serialized as a map of the fields it sets, e.g.
+/// `{"fg": "#ff0000", "text_styles": ["bold"], "underline": "normal"}`.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Color, Style};
+///
+/// let warning = Style::new().fg(Color::ORANGE).underline(None);
+///
+/// println!("{}", warning.apply("Disk almost full"));
+/// println!("{}", warning.paint(42));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Style {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) fg: Option<TermColor>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) bg: Option<TermColor>,
+    /// The enabled text styles, one bit per `TextStyle`
+    #[cfg_attr(feature = "serde", serde(with = "text_styles_serde", skip_serializing_if = "no_text_styles"))]
+    pub(crate) text_styles: u16,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) underline: Option<UnderlineStyle>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) underline_color: Option<Color>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) mode: Option<ColorMode>,
+    /// Colors every character of the text instead of `fg`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) gradient: Option<Gradient>,
+    /// Colors the background of every character instead of `bg`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) bg_gradient: Option<Gradient>,
+    /// Erases the rest of the line with the background before the reset
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub(crate) fill_line: bool,
+    /// Writes no color code next to a tab in the per-character painters
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub(crate) tab_safe: bool,
+}
+
+#[cfg(feature = "serde")]
+fn no_text_styles(text_styles: &u16) -> bool {
+    *text_styles == 0
+}
+
+#[cfg(feature = "serde")]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// The text styles of a `Style` as a list of `TextStyle` names
+#[cfg(feature = "serde")]
+mod text_styles_serde {
+    use super::TextStyle;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(text_styles: &u16, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(TextStyle::ALL.iter().filter(|style| text_styles & style.bit() != 0))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
+        let styles = Vec::<TextStyle>::deserialize(deserializer)?;
+        Ok(styles.iter().fold(0, |text_styles, style| text_styles | style.bit()))
+    }
+}
+
+impl Style {
+    /// Create a style without color nor underline
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    /// Set the color of the text
+    ///
+    /// # Params
+    /// - `r`: Red color value
+    /// - `g`: Green color value
+    /// - `b`: Blue color value
+    pub fn color(self, r: u8, g: u8, b: u8) -> Style {
+        self.fg(Color::rgb(r, g, b))
+    }
+
+    /// Set the color of the text from a [`Color`], an [`AnsiColor`](crate::AnsiColor) or a `(r, g, b)` tuple
+    ///
+    /// # Params
+    /// - `color`: The color of the text
+    pub fn fg(mut self, color: impl Into<TermColor>) -> Style {
+        self.fg = Some(color.into());
+        self
+    }
+
+    /// Set the background color of the text from a [`Color`], an [`AnsiColor`](crate::AnsiColor) or a `(r, g, b)` tuple
+    ///
+    /// # Params
+    /// - `color`: The background color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{AnsiColor, Color, Style};
+    ///
+    /// let badge = Style::new().fg(Color::WHITE).bg((40, 120, 200)).bold();
+    /// println!("{} {}", badge.apply(" PASS "), Style::new().bg(AnsiColor::Red).apply(" FAIL "));
+    /// ```
+    pub fn bg(mut self, color: impl Into<TermColor>) -> Style {
+        self.bg = Some(color.into());
+        self
+    }
+
+    ansi_color_methods!(impl Style);
+
+    /// Color the text with a gradient from `start` to `end` instead of a single color
+    ///
+    /// Unlike [`ColorPrint::gradient`] the gradient is part of the style, so it is combined
+    /// with the other attributes and printed with the color mode of the text.
+    ///
+    /// # Params
+    /// - `start`: The color of the first character
+    /// - `end`: The color of the last character
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, StyleExt};
+    ///
+    /// println!("{}", "Polychrome".styled().gradient(Color::RED, Color::BLUE).bold().underline(None));
+    /// ```
+    pub fn gradient(self, start: impl Into<Color>, end: impl Into<Color>) -> Style {
+        self.gradient_fill(Gradient::new(&[start.into(), end.into()]))
+    }
+
+    /// Color the text with a [`Gradient`] instead of a single color, see [`Style::gradient`]
+    ///
+    /// # Params
+    /// - `gradient`: The gradient from the first character to the last one
+    pub fn gradient_fill(mut self, gradient: Gradient) -> Style {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// Color the background with a gradient from `start` to `end` instead of a single color
+    ///
+    /// The text keeps the color of the style, pick one readable over the whole gradient,
+    /// e.g. with [`Color::readable_fg`].
+    ///
+    /// # Params
+    /// - `start`: The background of the first character
+    /// - `end`: The background of the last character
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Style};
+    ///
+    /// let (start, end) = (Color::rgb(20, 30, 90), Color::rgb(90, 20, 110));
+    /// let header = Style::new().gradient_bg(start, end).fg(start.readable_fg()).bold();
+    /// println!("{}", header.apply("  polychrome 2.0  "));
+    /// ```
+    pub fn gradient_bg(self, start: impl Into<Color>, end: impl Into<Color>) -> Style {
+        self.gradient_bg_fill(Gradient::new(&[start.into(), end.into()]))
+    }
+
+    /// Color the background with a [`Gradient`] instead of a single color, see [`Style::gradient_bg`]
+    ///
+    /// # Params
+    /// - `gradient`: The gradient from the first character to the last one
+    pub fn gradient_bg_fill(mut self, gradient: Gradient) -> Style {
+        self.bg_gradient = Some(gradient);
+        self
+    }
+
+    /// Color the text with one of the [`gradients`](crate::gradients) presets
+    ///
+    /// # Params
+    /// - `preset`: The preset
+    pub fn gradient_preset(self, preset: Preset) -> Style {
+        self.gradient_fill(preset.gradient())
+    }
+
+    /// Set the underline style of the text
+    ///
+    /// # Params
+    /// - `style`: The underline style, `None` for a normal underline. `double`, `curly`, `dotted`
+    ///   and `dashed` change its shape, see [`UnderlineStyle`]. `stripe` is kept as a deprecated
+    ///   alias replacing the underline with [`Style::strikethrough`].
+    pub fn underline(mut self, style: Option<&str>) -> Style {
+        self.underline = match style {
+            Some(style @ ("upper" | "double" | "curly" | "dotted" | "dashed")) => {
+                Some(UnderlineStyle::String(style.to_string()))
+            }
+            Some("stripe") => return Style { underline: None, ..self }.strikethrough(),
+            _ => Some(UnderlineStyle::None),
+        };
+        self
+    }
+
+    /// Enable a text style, several can be enabled at once
+    ///
+    /// # Params
+    /// - `style`: The text style
+    pub fn text_style(mut self, style: TextStyle) -> Style {
+        self.text_styles |= style.bit();
+        self
+    }
+
+    /// Print the text in bold
+    pub fn bold(self) -> Style {
+        self.text_style(TextStyle::Bold)
+    }
+
+    /// Print the text with decreased intensity
+    pub fn dim(self) -> Style {
+        self.text_style(TextStyle::Dim)
+    }
+
+    /// Print the text in italic
+    pub fn italic(self) -> Style {
+        self.text_style(TextStyle::Italic)
+    }
+
+    /// Draw a line through the text, it can be combined with an underline
+    pub fn strikethrough(self) -> Style {
+        self.text_style(TextStyle::Strikethrough)
+    }
+
+    /// Replace the underline with a line through the text, like `underline(Some("stripe"))`
+    #[deprecated(since = "2.0.3", note = "use `strikethrough`, which can be combined with an underline")]
+    pub fn stripe(self) -> Style {
+        self.underline(Some("stripe"))
+    }
+
+    /// Draw a line above the text (SGR 53), not supported by every terminal
+    pub fn overline(self) -> Style {
+        self.text_style(TextStyle::Overline)
+    }
+
+    /// Return true if the text style is enabled
+    pub fn has_text_style(&self, style: TextStyle) -> bool {
+        self.text_styles & style.bit() != 0
+    }
+
+    /// Set the color of the underline, shown by terminals supporting colored underlines
+    ///
+    /// # Params
+    /// - `r`: Red color value
+    /// - `g`: Green color value
+    /// - `b`: Blue color value
+    pub fn underline_color(mut self, r: u8, g: u8, b: u8) -> Style {
+        self.underline_color = Some(Color::rgb(r, g, b));
+        self
+    }
+
+    /// Set the kind of escape codes used to print the color
+    ///
+    /// # Params
+    /// - `mode`: The color mode, the global one ([`ColorMode::global`]) by default
+    pub fn mode(mut self, mode: ColorMode) -> Style {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Fill the rest of the line after the text with the background color
+    ///
+    /// An erase to the end of the line (`\x1b[K`) is written before the reset, it takes the
+    /// current background, so a status bar or a highlighted row covers the whole terminal width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Style;
+    ///
+    /// println!("{}", Style::new().bg((0, 0, 128)).fill_line().apply(" 3 files changed"));
+    /// ```
+    pub fn fill_line(mut self) -> Style {
+        self.fill_line = true;
+        self
+    }
+
+    /// Keep the escape codes away from the tabs of a text painted character by character
+    ///
+    /// The tabs are kept for tab-aware consumers like a TSV reader. With a gradient, a tab and the
+    /// character after it take the color of the character before the tab, the color change is
+    /// written in front of the next character, so no escape code touches a tab in the middle of
+    /// the text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::Style;
+    ///
+    /// let row = Style::new().gradient((255, 0, 0), (0, 0, 255)).tab_safe().apply("name\tvalue");
+    /// println!("{}", row);
+    /// ```
+    pub fn tab_safe(mut self) -> Style {
+        self.tab_safe = true;
+        self
+    }
+
+    /// Combine two styles, the fields set in `other` override the ones of this style
+    ///
+    /// Fields not set in `other` (no color, no underline, ...) keep the value of this style,
+    /// so a base theme style can be patched with per-call overrides. The text styles of
+    /// both are enabled.
+    ///
+    /// # Params
+    /// - `other`: The style with the overrides
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Style};
+    ///
+    /// let error = Style::new().fg(Color::RED);
+    /// let emphasis = Style::new().underline(None);
+    ///
+    /// let style = error.merge(&emphasis);
+    /// assert_eq!(style.apply("failed").render_debug(), "[fg:255,0,0][underline]failed[reset]");
+    /// ```
+    pub fn merge(&self, other: &Style) -> Style {
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            text_styles: self.text_styles | other.text_styles,
+            underline: other.underline.clone().or_else(|| self.underline.clone()),
+            underline_color: other.underline_color.or(self.underline_color),
+            mode: other.mode.or(self.mode),
+            gradient: other.gradient.clone().or_else(|| self.gradient.clone()),
+            bg_gradient: other.bg_gradient.clone().or_else(|| self.bg_gradient.clone()),
+            fill_line: self.fill_line || other.fill_line,
+            tab_safe: self.tab_safe || other.tab_safe,
+        }
+    }
+
+    /// Keep the style only if `condition` is true, otherwise return an empty style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{Color, Style};
+    ///
+    /// for (name, passed) in [("parse", true), ("render", false)] {
+    ///     println!("{}", Style::new().fg(Color::RED).when(!passed).apply(name));
+    /// }
+    /// ```
+    pub fn when(self, condition: bool) -> Style {
+        if condition { self } else { Style::new() }
+    }
+
+    /// Keep the style only if `condition` is false, the opposite of [`Style::when`]
+    pub fn unless(self, condition: bool) -> Style {
+        self.when(!condition)
+    }
+
+    /// Apply the style to a text, borrowed or owned
+    ///
+    /// # Params
+    /// - `text`: The text to style, a `&str`, a `String` or a `Cow<str>`
+    pub fn apply<'a>(&self, text: impl Into<Cow<'a, str>>) -> ColorPrint<'a> {
+        ColorPrint::from(text.into()).with_style(self.clone())
+    }
+
+    /// Apply the style to anything printable, like numbers or paths
+    ///
+    /// # Params
+    /// - `value`: The value to style, formatted with its `Display` implementation
+    pub fn paint(&self, value: impl Display) -> ColorPrint<'static> {
+        self.apply(value.to_string())
+    }
+
+    /// Return true if the style has no color, text style, underline or gradient, the color mode aside
+    #[cfg(feature = "ansi")]
+    pub(crate) fn is_plain(&self) -> bool {
+        self.fg.is_none()
+            && self.bg.is_none()
+            && self.text_styles == 0
+            && self.underline.is_none()
+            && self.underline_color.is_none()
+            && self.gradient.as_ref().is_none_or(Gradient::is_empty)
+            && self.bg_gradient.as_ref().is_none_or(Gradient::is_empty)
+            && !self.fill_line
+    }
+
+    /// Write the value between the escape codes of the style, or as it is for a plain style
+    #[cfg(feature = "ansi")]
+    pub(crate) fn write_styled(&self, f: &mut std::fmt::Formatter<'_>, value: &dyn Display) -> std::fmt::Result {
+        let mode = crate::ColorChoice::global().resolve(self.mode);
+        if mode == ColorMode::None || self.is_plain() {
+            return write!(f, "{}", value);
+        }
+        let fg_gradient = self.gradient.as_ref().filter(|gradient| !gradient.is_empty());
+        let bg_gradient = self.bg_gradient.as_ref().filter(|gradient| !gradient.is_empty());
+        if fg_gradient.is_some() || bg_gradient.is_some() {
+            return self.write_gradient(f, &value.to_string(), fg_gradient, bg_gradient, mode);
+        }
+
+        self.write_escape_codes(f, mode)?;
+        // A styled value nested inside resets everything when it ends, the style starts again after it
+        let mut nested = RestoreAfterReset { f, style: self, mode, state: 0 };
+        std::fmt::Write::write_fmt(&mut nested, format_args!("{}", value))?;
+        self.write_reset(f)
+    }
+
+    /// Write the reset at the end of the text, after the erase to the end of the line of [`Style::fill_line`]
+    #[cfg(feature = "ansi")]
+    fn write_reset(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.fill_line {
+            f.write_str("\x1b[K")?;
+        }
+        f.write_str(crate::utils::reset_code())
+    }
+
+    /// Write the text with a color and a background per character from the gradients and the other
+    /// attributes of the style
+    ///
+    /// Escape codes in the text are written as they are and don't take a color, the attributes
+    /// start again after a reset. The background gradient stops at the line breaks, so it doesn't
+    /// fill the rest of the line.
+    #[cfg(feature = "ansi")]
+    pub(crate) fn write_gradient(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        text: &str,
+        fg: Option<&Gradient>,
+        bg: Option<&Gradient>,
+        mode: ColorMode,
+    ) -> std::fmt::Result {
+        let attributes = Style {
+            fg: if fg.is_some() { None } else { self.fg },
+            bg: if bg.is_some() { None } else { self.bg },
+            gradient: None,
+            bg_gradient: None,
+            ..self.clone()
+        };
+        let plain = if text.contains('\x1b') {
+            Cow::Owned(crate::utils::strip_ansi(text))
+        } else {
+            Cow::Borrowed(text)
+        };
+        let layout = crate::gradient::TextLayout::new(&plain);
+        let (mut previous_fg, mut previous_bg) = (None, None);
+        let mut positions = crate::gradient::CharPositions::default();
+        let mut rest = text;
+
+        let compact = crate::utils::compact_colors();
+        // The previous character was a tab, see `Style::tab_safe`
+        let mut after_tab = false;
+        attributes.write_escape_codes(f, mode)?;
+        while let Some(grapheme) = crate::utils::graphemes(rest).next() {
+            if !compact {
+                (previous_fg, previous_bg) = (None, None);
+            }
+            if grapheme.starts_with('\x1b') {
+                let escape = &rest[..crate::utils::escape_len(rest)];
+                f.write_str(escape)?;
+                if escape == "\x1b[0m" || escape == "\x1b[m" {
+                    attributes.write_escape_codes(f, mode)?;
+                    (previous_fg, previous_bg) = (None, None);
+                }
+                rest = &rest[escape.len()..];
+                continue;
+            }
+            if self.tab_safe && (grapheme == "\t" || after_tab) {
+                after_tab = grapheme == "\t";
+                f.write_str(grapheme)?;
+                rest = &rest[grapheme.len()..];
+                continue;
+            }
+            let line_break = grapheme.ends_with('\n');
+            if line_break && bg.is_some() {
+                f.write_str("\x1b[49m")?;
+                previous_bg = None;
+            }
+
+            let position = positions.position_of(grapheme);
+            let fg_color = fg.map(|gradient| gradient.at_char(&layout, &position));
+            let bg_color = bg.filter(|_| !line_break).map(|gradient| gradient.at_char(&layout, &position));
+            let mut sgr = SgrWriter::new(f);
+            if fg_color != previous_fg {
+                sgr.param(fg_color.and_then(|color| mode.fg_param(color)))?;
+                previous_fg = fg_color;
+            }
+            if bg_color != previous_bg {
+                sgr.param(bg_color.and_then(|color| mode.bg_param(color)))?;
+                previous_bg = bg_color;
+            }
+            sgr.finish()?;
+
+            f.write_str(grapheme)?;
+            rest = &rest[grapheme.len()..];
+        }
+        self.write_reset(f)
+    }
+
+    /// Write only the value, the `ansi` feature is disabled
+    #[cfg(not(feature = "ansi"))]
+    pub(crate) fn write_styled(&self, f: &mut std::fmt::Formatter<'_>, value: &dyn Display) -> std::fmt::Result {
+        write!(f, "{}", value)
+    }
+
+    /// Write the escape code starting the style in the given mode
+    ///
+    /// Every parameter goes in a single SGR sequence, e.g. `\x1b[38;2;255;0;0;4m`, nothing is
+    /// written for an empty style.
+    #[cfg(feature = "ansi")]
+    pub(crate) fn write_escape_codes(&self, f: &mut dyn std::fmt::Write, mode: ColorMode) -> std::fmt::Result {
+        if mode == ColorMode::None {
+            return Ok(());
+        }
+
+        let mut sgr = SgrWriter::new(f);
+        match self.fg {
+            Some(TermColor::Rgb(color)) => sgr.param(mode.fg_param(color))?,
+            Some(TermColor::Ansi(color)) => sgr.param(Some(color.fg_param()))?,
+            None => {}
+        }
+        match self.bg {
+            Some(TermColor::Rgb(color)) => sgr.param(mode.bg_param(color))?,
+            Some(TermColor::Ansi(color)) => sgr.param(Some(color.bg_param()))?,
+            None => {}
+        }
+        for style in TextStyle::ALL.iter().filter(|style| self.has_text_style(**style)) {
+            sgr.param(Some(style.sgr_param()))?;
+        }
+        let underline = match &self.underline {
+            Some(UnderlineStyle::String(style)) if style == "double" => {
+                Some(crate::DoubleUnderline::global().sgr_param())
+            }
+            Some(style) if style.is_shape() && !crate::utils::supports_underline_styles() => {
+                style.degrade().sgr_param()
+            }
+            Some(style) => style.sgr_param(),
+            None => None,
+        };
+        sgr.param(underline)?;
+        sgr.param(self.underline_color.and_then(|color| mode.underline_color_param(color)))?;
+        sgr.finish()
+    }
+
+    /// Return the escape code starting the style in the given mode, for output composed by hand
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorMode, Style};
+    ///
+    /// let style = Style::new().color(255, 0, 0).underline(None);
+    /// assert_eq!(style.sgr_code(ColorMode::TrueColor), "\x1b[38;2;255;0;0;4m");
+    /// assert_eq!(style.sgr_code(ColorMode::Ansi16), "\x1b[91;4m");
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn sgr_code(&self, mode: ColorMode) -> String {
+        let mut codes = String::new();
+        // Writing into a String never fails
+        let _ = self.write_escape_codes(&mut codes, mode);
+        codes
+    }
+
+    /// Update the style with the parameters of a SGR sequence, e.g. `1;38;5;208` from `\x1b[1;38;5;208m`
+    ///
+    /// The colors can also be written with colons, e.g. `38:2::255:0:0` or `58:5:208`.
+    /// Unknown parameters are ignored.
+    pub(crate) fn apply_sgr(&mut self, params: &str) {
+        let mut params = params.split(';');
+        while let Some(param) = params.next() {
+            let (code, sub) = param.split_once(':').unwrap_or((param, ""));
+            let code: u16 = if code.is_empty() { 0 } else { code.parse().unwrap_or(u16::MAX) };
+            let mut color = || {
+                if sub.is_empty() {
+                    return sgr_color(&mut params);
+                }
+                // `2:colorspace:r:g:b` with the color space usually empty, or `2:r:g:b`
+                let mut parts: Vec<&str> = sub.split(':').collect();
+                if parts.len() == 5 && parts[0] == "2" {
+                    parts.remove(1);
+                }
+                sgr_color(parts.into_iter())
+            };
+            let clear = |style: &mut Style, styles: &[TextStyle]| {
+                for text_style in styles {
+                    style.text_styles &= !text_style.bit();
+                }
+            };
+
+            match code {
+                0 => *self = Style { mode: self.mode, ..Style::new() },
+                4 => {
+                    self.underline = match sub {
+                        "0" => None,
+                        "2" => Some(UnderlineStyle::String("double".to_string())),
+                        "3" => Some(UnderlineStyle::String("curly".to_string())),
+                        "4" => Some(UnderlineStyle::String("dotted".to_string())),
+                        "5" => Some(UnderlineStyle::String("dashed".to_string())),
+                        _ => Some(UnderlineStyle::None),
+                    }
+                }
+                21 => self.underline = Some(UnderlineStyle::String("double".to_string())),
+                22 => clear(self, &[TextStyle::Bold, TextStyle::Dim]),
+                23 => clear(self, &[TextStyle::Italic]),
+                24 => self.underline = None,
+                25 => clear(self, &[TextStyle::Blink, TextStyle::RapidBlink]),
+                27 => clear(self, &[TextStyle::Reverse]),
+                28 => clear(self, &[TextStyle::Conceal]),
+                29 => clear(self, &[TextStyle::Strikethrough]),
+                50 => clear(self, &[TextStyle::ProportionalSpacing]),
+                55 => clear(self, &[TextStyle::Overline]),
+                75 => clear(self, &[TextStyle::Superscript, TextStyle::Subscript]),
+                30..=37 | 90..=97 => {
+                    let index = if code < 90 { code - 30 } else { code - 90 + 8 };
+                    self.fg = crate::AnsiColor::from_index(index as u8).map(TermColor::Ansi);
+                }
+                38 => self.fg = color().or(self.fg),
+                39 => self.fg = None,
+                40..=47 | 100..=107 => {
+                    let index = if code < 100 { code - 40 } else { code - 100 + 8 };
+                    self.bg = crate::AnsiColor::from_index(index as u8).map(TermColor::Ansi);
+                }
+                48 => self.bg = color().or(self.bg),
+                49 => self.bg = None,
+                58 => {
+                    self.underline_color = match color() {
+                        Some(TermColor::Rgb(color)) => Some(color),
+                        Some(TermColor::Ansi(color)) => Some(color.into()),
+                        None => self.underline_color,
+                    }
+                }
+                59 => self.underline_color = None,
+                _ => {
+                    if let Some(text_style) = TextStyle::ALL.iter().find(|style| style.sgr_param() as u16 == code) {
+                        self.text_styles |= text_style.bit();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return the readable tokens of the style used by [`ColorPrint::render_debug`]
+    pub(crate) fn debug_tokens(&self) -> String {
+        let color_token = match self.fg {
+            Some(TermColor::Rgb(Color { r, g, b })) => format!("[fg:{},{},{}]", r, g, b),
+            Some(TermColor::Ansi(color)) => format!("[fg:{}]", color.name()),
+            None => "".to_string(),
+        };
+
+        let bg_token = match self.bg {
+            Some(TermColor::Rgb(Color { r, g, b })) => format!("[bg:{},{},{}]", r, g, b),
+            Some(TermColor::Ansi(color)) => format!("[bg:{}]", color.name()),
+            None => "".to_string(),
+        };
+
+        let text_style_tokens: String = TextStyle::ALL
+            .iter()
+            .filter(|style| self.has_text_style(**style))
+            .map(|style| format!("[{}]", style.name()))
+            .collect();
+
+        let underline_token = match &self.underline {
+            Some(UnderlineStyle::String(style)) => match style.as_str() {
+                "normal" => "[underline]",
+                "stripe" => "[stripe]",
+                "double" => "[double_underline]",
+                "curly" => "[curly_underline]",
+                "dotted" => "[dotted_underline]",
+                "dashed" => "[dashed_underline]",
+                _ => "",
+            },
+            Some(UnderlineStyle::None) => "[underline]",
+            None => "",
+        };
+
+        let underline_color_token = match self.underline_color {
+            Some(Color { r, g, b }) => format!("[underline_color:{},{},{}]", r, g, b),
+            None => "".to_string(),
+        };
+
+        let gradient_token = |name: &str, gradient: &Option<Gradient>| match gradient {
+            Some(gradient) => {
+                let stops: Vec<String> = gradient.stops().iter().map(|(_, color)| color.to_hex()).collect();
+                format!("[{}:{}]", name, stops.join(","))
+            }
+            None => "".to_string(),
+        };
+
+        format!(
+            "{}{}{}{}{}{}{}",
+            color_token,
+            gradient_token("gradient", &self.gradient),
+            bg_token,
+            gradient_token("gradient_bg", &self.bg_gradient),
+            text_style_tokens,
+            underline_token,
+            underline_color_token
+        )
+    }
+}
+
+/// Parse the color following a `38`, `48` or `58` parameter: `2;r;g;b` or `5;index`
+fn sgr_color<'s>(mut params: impl Iterator<Item = &'s str>) -> Option<TermColor> {
+    match params.next() {
+        Some("2") => {
+            let mut channel = || params.next().and_then(|channel| channel.parse::<u8>().ok());
+            Some(TermColor::Rgb(Color::rgb(channel()?, channel()?, channel()?)))
+        }
+        Some("5") => {
+            // The first 16 indexes are the theme colors
+            let index = params.next()?.parse::<u8>().ok()?;
+            match crate::AnsiColor::from_index(index) {
+                Some(color) => Some(TermColor::Ansi(color)),
+                None => Some(TermColor::Rgb(Color::from_ansi256(index))),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Writes SGR parameters separated by `;` in a single escape sequence
+#[cfg(feature = "ansi")]
+struct SgrWriter<'a> {
+    f: &'a mut dyn std::fmt::Write,
+    started: bool,
+}
+
+#[cfg(feature = "ansi")]
+impl<'a> SgrWriter<'a> {
+    fn new(f: &'a mut dyn std::fmt::Write) -> SgrWriter<'a> {
+        SgrWriter { f, started: false }
+    }
+
+    /// Write the parameter if there is one, starting the sequence before the first one
+    fn param(&mut self, param: Option<impl Display>) -> std::fmt::Result {
+        if let Some(param) = param {
+            write!(self.f, "{}{}", if self.started { ";" } else { "\x1b[" }, param)?;
+            self.started = true;
+        }
+        Ok(())
+    }
+
+    /// End the sequence, nothing is written if there were no parameters
+    fn finish(self) -> std::fmt::Result {
+        if self.started {
+            self.f.write_char('m')?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the escape codes of the enclosing style again after every reset in the written text
+///
+/// The reset sequence can be split between several writes, `state` is how much of it was seen.
+#[cfg(feature = "ansi")]
+struct RestoreAfterReset<'a, 'f> {
+    f: &'a mut std::fmt::Formatter<'f>,
+    style: &'a Style,
+    mode: ColorMode,
+    state: u8,
+}
+
+#[cfg(feature = "ansi")]
+impl std::fmt::Write for RestoreAfterReset<'_, '_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let mut written = 0;
+        for (index, byte) in s.bytes().enumerate() {
+            // Follows `\x1b[0m` and `\x1b[m`
+            self.state = match (self.state, byte) {
+                (_, b'\x1b') => 1,
+                (1, b'[') => 2,
+                (2, b'0') => 3,
+                (2 | 3, b'm') => {
+                    self.f.write_str(&s[written..=index])?;
+                    written = index + 1;
+                    self.style.write_escape_codes(self.f, self.mode)?;
+                    0
+                }
+                _ => 0,
+            };
+        }
+        self.f.write_str(&s[written..])
+    }
+}
+
+/// Any printable value with a [`Style`], created with [`StyleExt::styled`]
+///
+/// The value is formatted when printed, without converting it to a `String` first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Styled<T>(T, Style);
+
+impl<T: Display> Styled<T> {
+    /// Get the style of the value
+    pub fn style(&self) -> &Style {
+        &self.1
+    }
+
+    /// Replace the style of the value
+    ///
+    /// # Params
+    /// - `style`: The new style
+    pub fn with_style(self, style: Style) -> Styled<T> {
+        Styled(self.0, style)
+    }
+
+    /// Set the color of the value, see [`Style::color`]
+    pub fn color(self, r: u8, g: u8, b: u8) -> Styled<T> {
+        Styled(self.0, self.1.color(r, g, b))
+    }
+
+    /// Set the color of the value, see [`Style::fg`]
+    pub fn fg(self, color: impl Into<TermColor>) -> Styled<T> {
+        Styled(self.0, self.1.fg(color))
+    }
+
+    /// Set the background color of the value, see [`Style::bg`]
+    pub fn bg(self, color: impl Into<TermColor>) -> Styled<T> {
+        Styled(self.0, self.1.bg(color))
+    }
+
+    ansi_color_methods!(impl Styled<T>);
+
+    /// Color the value with a gradient from `start` to `end`, see [`Style::gradient`]
+    pub fn gradient(self, start: impl Into<Color>, end: impl Into<Color>) -> Styled<T> {
+        Styled(self.0, self.1.gradient(start, end))
+    }
+
+    /// Color the value with a [`Gradient`], see [`Style::gradient_fill`]
+    pub fn gradient_fill(self, gradient: Gradient) -> Styled<T> {
+        Styled(self.0, self.1.gradient_fill(gradient))
+    }
+
+    /// Color the background of the value with a gradient from `start` to `end`, see [`Style::gradient_bg`]
+    pub fn gradient_bg(self, start: impl Into<Color>, end: impl Into<Color>) -> Styled<T> {
+        Styled(self.0, self.1.gradient_bg(start, end))
+    }
+
+    /// Color the background of the value with a [`Gradient`], see [`Style::gradient_bg_fill`]
+    pub fn gradient_bg_fill(self, gradient: Gradient) -> Styled<T> {
+        Styled(self.0, self.1.gradient_bg_fill(gradient))
+    }
+
+    /// Color the value with a gradient preset, see [`Style::gradient_preset`]
+    pub fn gradient_preset(self, preset: Preset) -> Styled<T> {
+        Styled(self.0, self.1.gradient_preset(preset))
+    }
+
+    /// Set the underline style of the value, see [`Style::underline`]
+    pub fn underline(self, style: Option<&str>) -> Styled<T> {
+        Styled(self.0, self.1.underline(style))
+    }
+
+    /// Enable a text style, see [`Style::text_style`]
+    pub fn text_style(self, style: TextStyle) -> Styled<T> {
+        Styled(self.0, self.1.text_style(style))
+    }
+
+    /// Print the value in bold, see [`Style::bold`]
+    pub fn bold(self) -> Styled<T> {
+        Styled(self.0, self.1.bold())
+    }
+
+    /// Print the value with decreased intensity, see [`Style::dim`]
+    pub fn dim(self) -> Styled<T> {
+        Styled(self.0, self.1.dim())
+    }
+
+    /// Print the value in italic, see [`Style::italic`]
+    pub fn italic(self) -> Styled<T> {
+        Styled(self.0, self.1.italic())
+    }
+
+    /// Draw a line through the value, see [`Style::strikethrough`]
+    pub fn strikethrough(self) -> Styled<T> {
+        Styled(self.0, self.1.strikethrough())
+    }
+
+    /// Draw a line above the value, see [`Style::overline`]
+    pub fn overline(self) -> Styled<T> {
+        Styled(self.0, self.1.overline())
+    }
+
+    /// Set the color of the underline, see [`Style::underline_color`]
+    pub fn underline_color(self, r: u8, g: u8, b: u8) -> Styled<T> {
+        Styled(self.0, self.1.underline_color(r, g, b))
+    }
+
+    /// Set the kind of escape codes used to print the color, see [`Style::mode`]
+    pub fn mode(self, mode: ColorMode) -> Styled<T> {
+        Styled(self.0, self.1.mode(mode))
+    }
+
+    /// Fill the rest of the line with the background color, see [`Style::fill_line`]
+    pub fn fill_line(self) -> Styled<T> {
+        Styled(self.0, self.1.fill_line())
+    }
+
+    /// Keep the escape codes away from the tabs, see [`Style::tab_safe`]
+    pub fn tab_safe(self) -> Styled<T> {
+        Styled(self.0, self.1.tab_safe())
+    }
+
+    /// Keep the style only if `condition` is true, see [`Style::when`]
+    pub fn when(self, condition: bool) -> Styled<T> {
+        Styled(self.0, self.1.when(condition))
+    }
+
+    /// Keep the style only if `condition` is false, see [`Style::unless`]
+    pub fn unless(self, condition: bool) -> Styled<T> {
+        Styled(self.0, self.1.unless(condition))
+    }
+}
+
+impl<T: Display> Display for Styled<T> {
+    /// Print the value with its style
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.1.write_styled(f, &self.0)
+    }
+}
+
+/// Style any printable value: numbers, paths, durations, ...
+///
+/// # Example
+///
+/// ```
+/// use polychrome::StyleExt;
+/// use std::path::Path;
+///
+/// println!("{} files in {}", 42.styled().color(255, 136, 0), Path::new("/tmp").display().styled().underline(None));
+/// ```
+pub trait StyleExt: Display {
+    /// Start styling the value, without style it is printed as is
+    fn styled(&self) -> Styled<&Self>;
+
+    ansi_color_methods!(trait);
+
+    /// Style the value with one of the [`gradients`](crate::gradients) presets
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::gradients::Preset;
+    /// use polychrome::StyleExt;
+    ///
+    /// println!("{}", "Hello, world!".gradient_preset(Preset::Ocean));
+    /// ```
+    fn gradient_preset(&self, preset: Preset) -> Styled<&Self> {
+        self.styled().gradient_preset(preset)
+    }
+}
+
+impl<T: Display + ?Sized> StyleExt for T {
+    fn styled(&self) -> Styled<&T> {
+        Styled(self, Style::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnsiColor;
+
+    #[test]
+    fn test_apply_style() {
+        let style = Style::new().color(255, 0, 0).underline(Some("stripe"));
+        let owned = String::from("owned");
+
+        assert_eq!(style.apply("borrowed").render_debug(), "[fg:255,0,0][strikethrough]borrowed[reset]");
+        assert_eq!(style.apply(owned).render_debug(), "[fg:255,0,0][strikethrough]owned[reset]");
+        assert_eq!(style.paint(4.5).render_debug(), "[fg:255,0,0][strikethrough]4.5[reset]");
+        assert_eq!(Style::new().paint('x').render_debug(), "x[reset]");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_escape_codes() {
+        let style = Style::new().color(1, 2, 3).underline(None).underline_color(4, 5, 6);
+
+        assert_eq!(style.sgr_code(ColorMode::TrueColor), "\x1b[38;2;1;2;3;4;58;2;4;5;6m");
+        assert_eq!(style.sgr_code(ColorMode::None), "");
+        assert_eq!(Style::new().sgr_code(ColorMode::TrueColor), "");
+    }
+
+    #[test]
+    fn test_merge() {
+        let base = Style::new().color(255, 0, 0).underline(Some("stripe")).mode(ColorMode::Ansi16);
+        let overrides = Style::new().color(0, 0, 255).underline_color(1, 2, 3);
+
+        let merged = base.merge(&overrides);
+        assert_eq!(merged.apply("x").render_debug(), "[fg:0,0,255][strikethrough][underline_color:1,2,3]x[reset]");
+        assert_eq!(merged.mode, Some(ColorMode::Ansi16));
+        assert_eq!(overrides.merge(&Style::new()).apply("x").render_debug(), overrides.apply("x").render_debug());
+    }
+
+    #[test]
+    fn test_when_unless() {
+        let style = Style::new().color(255, 0, 0);
+
+        assert_eq!(style.clone().when(true).apply("x").render_debug(), "[fg:255,0,0]x[reset]");
+        assert_eq!(style.clone().when(false).apply("x").render_debug(), "x[reset]");
+        assert_eq!(style.clone().unless(true).apply("x").render_debug(), "x[reset]");
+        assert_eq!(style.unless(false).apply("x").render_debug(), "[fg:255,0,0]x[reset]");
+    }
+
+    #[test]
+    fn test_style_display_values() {
+        let value = 42.styled().color(255, 0, 0).mode(ColorMode::None);
+        assert_eq!(value.to_string(), "42");
+        assert!(value.style().fg.is_some());
+        assert_eq!(1.5.styled().to_string(), "1.5");
+        assert_eq!(1.5.styled().mode(ColorMode::TrueColor).to_string(), "1.5");
+        assert_eq!(Style::new().mode(ColorMode::Ansi256).apply("plain").to_string(), "plain");
+        assert!("text".styled().color(255, 0, 0).when(false).style().fg.is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_styled_escape_codes() {
+        let value = 7.styled().color(0, 0, 255).mode(ColorMode::TrueColor);
+        assert_eq!(value.to_string(), "\x1b[38;2;0;0;255m7\x1b[0m");
+    }
+
+    #[test]
+    fn test_overline() {
+        let style = Style::new().color(255, 0, 0).overline().underline(None);
+
+        assert!(style.has_text_style(TextStyle::Overline));
+        assert_eq!(style.apply("x").render_debug(), "[fg:255,0,0][overline][underline]x[reset]");
+        assert!(Style::new().merge(&style).has_text_style(TextStyle::Overline));
+        assert_eq!("x".styled().overline().style().text_styles, TextStyle::Overline.bit());
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_overline_sgr_code() {
+        assert_eq!(Style::new().overline().sgr_code(ColorMode::TrueColor), "\x1b[53m");
+        assert_eq!(Style::new().color(1, 2, 3).overline().sgr_code(ColorMode::TrueColor), "\x1b[38;2;1;2;3;53m");
+    }
+
+    #[test]
+    fn test_text_styles() {
+        for (i, style) in TextStyle::ALL.iter().enumerate() {
+            assert_eq!(*style as usize, i);
+        }
+        assert!(TextStyle::ALL.windows(2).all(|pair| pair[0].sgr_param() < pair[1].sgr_param()));
+        assert_eq!(TextStyle::Reveal.sgr_param(), 28);
+        assert_eq!(TextStyle::ProportionalSpacing.name(), "proportional_spacing");
+
+        let style = Style::new().bold().italic().text_style(TextStyle::RapidBlink);
+        assert_eq!(style.apply("x").render_debug(), "[bold][italic][rapid_blink]x[reset]");
+        assert!(!style.has_text_style(TextStyle::Dim));
+        assert_eq!("x".styled().dim().style().text_styles, TextStyle::Dim.bit());
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_text_styles_sgr_code() {
+        let style = Style::new().color(1, 2, 3).bold().text_style(TextStyle::Superscript).underline(None);
+        assert_eq!(style.sgr_code(ColorMode::TrueColor), "\x1b[38;2;1;2;3;1;73;4m");
+        assert_eq!(Style::new().text_style(TextStyle::Reveal).sgr_code(ColorMode::Ansi16), "\x1b[28m");
+
+        assert_eq!(TextStyle::Bold.sgr_code(), "\x1b[1m");
+        assert_eq!(TextStyle::Italic.sgr_code(), "\x1b[3m");
+        for style in TextStyle::ALL {
+            assert_eq!(style.sgr_code(), format!("\x1b[{}m", style.sgr_param()));
+        }
+    }
+
+    #[test]
+    fn test_strikethrough_with_underline() {
+        let both = Style::new().underline(None).strikethrough();
+        assert_eq!(both.apply("x").render_debug(), "[strikethrough][underline]x[reset]");
+
+        // The deprecated `stripe` underline still replaces the underline
+        let stripe = Style::new().underline(None).underline(Some("stripe"));
+        assert_eq!(stripe.apply("x").render_debug(), "[strikethrough]x[reset]");
+        #[allow(deprecated)]
+        let alias = Style::new().underline(None).stripe();
+        assert_eq!(alias, stripe);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_style_serde() {
+        let style = Style::new().fg(crate::AnsiColor::Red).bold().underline(None).underline_color(0, 0, 255);
+        let json = serde_json::to_string(&style).unwrap();
+        assert_eq!(json, r##"{"fg":"red","text_styles":["bold"],"underline":"normal","underline_color":"#0000ff"}"##);
+
+        let parsed: Style = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.apply("x").render_debug(), style.apply("x").render_debug());
+        assert_eq!(serde_json::to_string(&Style::new()).unwrap(), "{}");
+
+        let parsed: Style = serde_json::from_str(r#"{"fg": "tomato", "mode": "ansi256"}"#).unwrap();
+        assert_eq!(parsed.apply("x").render_debug(), "[fg:255,99,71]x[reset]");
+        assert_eq!(parsed.mode, Some(ColorMode::Ansi256));
+        assert!(serde_json::from_str::<Style>(r#"{"text_styles": ["sparkly"]}"#).is_err());
+    }
+
+    #[test]
+    fn test_style_eq() {
+        assert_eq!(Style::new().bold().color(1, 2, 3), Style::new().color(1, 2, 3).bold());
+        assert_ne!(Style::new().bold(), Style::new().italic());
+        assert_eq!(Style::new().merge(&Style::new().dim()), Style::new().dim());
+        assert_eq!(1.styled().bold(), 1.styled().bold());
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_nested_styles() {
+        use crate::ColorPrintExt;
+
+        let inner = "inner";
+        let inner = inner.color(0, 0, 255).mode(ColorMode::TrueColor);
+        let outer = Style::new().bold().mode(ColorMode::TrueColor);
+
+        assert_eq!(
+            outer.paint(format!("a {} b", inner)).to_string(),
+            "\x1b[1ma \x1b[38;2;0;0;255;4minner\x1b[0m\x1b[1m b\x1b[0m"
+        );
+        assert_eq!(
+            outer.paint(format_args!("a {} b", inner)).to_string(),
+            "\x1b[1ma \x1b[38;2;0;0;255;4minner\x1b[0m\x1b[1m b\x1b[0m"
+        );
+        assert_eq!(outer.paint("a\x1b[mb").to_string(), "\x1b[1ma\x1b[m\x1b[1mb\x1b[0m");
+        assert_eq!(outer.paint("a\x1b[1mb").to_string(), "\x1b[1ma\x1b[1mb\x1b[0m");
+        assert_eq!(Style::new().paint("a\x1b[0mb").mode(ColorMode::TrueColor).to_string(), "a\x1b[0mb");
+    }
+
+    #[test]
+    fn test_ansi_color_shortcuts() {
+        assert_eq!(Style::new().red().on_blue(), Style::new().fg(AnsiColor::Red).bg(AnsiColor::Blue));
+        assert_eq!("err".red().on_black().bold().style(), &Style::new().red().on_black().bold());
+        assert_eq!(42.bright_green().style(), &Style::new().fg(AnsiColor::BrightGreen));
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_fill_line() {
+        let row = Style::new().bg((0, 0, 128)).fill_line().mode(ColorMode::TrueColor);
+        assert_eq!(row.apply("ok").to_string(), "\x1b[48;2;0;0;128mok\x1b[K\x1b[0m");
+        let gradient = Style::new().gradient_bg(Color::RED, Color::BLUE).fill_line().mode(ColorMode::TrueColor);
+        assert!(gradient.apply("ab").to_string().ends_with("b\x1b[K\x1b[0m"));
+        let painted = ColorPrint::from("ok").map_style(|style| style.fg(Color::BLACK)).bg(Color::WHITE).fill_line();
+        let painted = painted.mode(ColorMode::Ansi16);
+        assert_eq!(painted.to_string(), "\x1b[30;107mok\x1b[K\x1b[0m");
+        assert!(Style::new().merge(&row).fill_line);
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_parse_background() {
+        let mut style = Style::new();
+        style.apply_sgr("41;48;2;1;2;3");
+        assert_eq!(style, Style::new().bg((1, 2, 3)));
+        style.apply_sgr("102");
+        assert_eq!(style, Style::new().bg(AnsiColor::BrightGreen));
+        style.apply_sgr("49");
+        assert_eq!(style, Style::new());
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_parse_colon_colors() {
+        let mut style = Style::new();
+        style.apply_sgr("38:2::1:2:3;1");
+        assert_eq!(style, Style::new().fg((1, 2, 3)).bold());
+        style.apply_sgr("0;48:2:4:5:6");
+        assert_eq!(style, Style::new().bg((4, 5, 6)));
+        style.apply_sgr("0;38:5:208;58:2::7:8:9");
+        assert_eq!(style, Style::new().fg(Color::from_ansi256(208)).underline_color(7, 8, 9));
+        style.apply_sgr("0;38:5:1");
+        assert_eq!(style, Style::new().fg(AnsiColor::Red));
+
+        // A broken color is ignored without eating the next parameters
+        style.apply_sgr("0;38:2::1;3");
+        assert_eq!(style, Style::new().italic());
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_style() {
+        let style = Style::new().gradient(Color::RED, Color::BLUE).bold().mode(ColorMode::TrueColor);
+        assert_eq!(
+            style.paint("abc").to_string(),
+            "\x1b[1m\x1b[38;2;255;0;0ma\x1b[38;2;128;0;128mb\x1b[38;2;0;0;255mc\x1b[0m"
+        );
+        assert_eq!(
+            style.clone().mode(ColorMode::Ansi16).paint("ab").to_string(),
+            "\x1b[1m\x1b[91ma\x1b[34mb\x1b[0m"
+        );
+
+        // Nested escape codes don't take a color
+        let nested = style.paint("a\x1b[0mb").to_string();
+        assert_eq!(nested, "\x1b[1m\x1b[38;2;255;0;0ma\x1b[0m\x1b[1m\x1b[38;2;0;0;255mb\x1b[0m");
+
+        let solid = Style::new().gradient_fill(Gradient::new(&[Color::RED])).mode(ColorMode::TrueColor);
+        assert_eq!(solid.paint("ab").to_string(), "\x1b[38;2;255;0;0mab\x1b[0m");
+        assert_eq!(style.apply("ab").render_debug(), "[gradient:#ff0000,#0000ff][bold]ab[reset]");
+        let styled = "ab".styled().gradient(Color::RED, Color::BLUE);
+        assert_eq!(styled.style(), &Style::new().gradient(Color::RED, Color::BLUE));
+    }
+
+    #[test]
+    fn test_gradient_preset() {
+        let sunset = "sunset".gradient_preset(Preset::Sunset).bold();
+        assert_eq!(sunset.style(), &Style::new().gradient_fill(Preset::Sunset.gradient()).bold());
+        assert_eq!(
+            ColorPrint::from("fire").gradient_preset(Preset::Fire).style(),
+            &Style::new().gradient_preset(Preset::Fire)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_vertical_gradient() {
+        let gradient = Gradient::new(&[Color::RED, Color::BLUE]).direction(crate::GradientDirection::Vertical);
+        let style = Style::new().gradient_fill(gradient).mode(ColorMode::TrueColor);
+        assert_eq!(
+            style.paint("ab\ncd").to_string(),
+            "\x1b[38;2;255;0;0mab\n\x1b[38;2;0;0;255mcd\x1b[0m"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_gradient_bg() {
+        let style = Style::new().gradient_bg(Color::RED, Color::BLUE).fg(Color::WHITE).mode(ColorMode::TrueColor);
+        assert_eq!(
+            style.paint("abc").to_string(),
+            "\x1b[38;2;255;255;255m\x1b[48;2;255;0;0ma\x1b[48;2;128;0;128mb\x1b[48;2;0;0;255mc\x1b[0m"
+        );
+        assert_eq!(style.apply("ab").render_debug(), "[fg:255,255,255][gradient_bg:#ff0000,#0000ff]ab[reset]");
+
+        // Both gradients share a single escape code, the background stops at line breaks
+        let both = Style::new().gradient(Color::BLACK, Color::WHITE).gradient_bg(Color::RED, Color::BLUE);
+        assert_eq!(
+            both.mode(ColorMode::TrueColor).paint("a\nb").to_string(),
+            "\x1b[38;2;0;0;0;48;2;255;0;0ma\x1b[49m\x1b[38;2;128;128;128m\n\x1b[38;2;255;255;255;48;2;0;0;255mb\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,498 @@
+// styled_line.rs
+use crate::{ColorPrint, Style};
+
+/// A line made of styled segments, each printed with its own color and underline
+///
+/// Created by adding [`ColorPrint`]s together or pushing segments one by one. Plain `&str`
+/// and `String` segments are printed without style.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{ColorPrint, ColorPrintExt, StyledLine};
+///
+/// let line = "error".color(255, 0, 0) + ColorPrint::from(": ") + "file not found".underline(None);
+/// println!("{}", line);
+///
+/// let prompt = StyledLine::new().with("user".color(0, 255, 0)).with("@").with("host".color(0, 0, 255));
+/// assert_eq!(prompt.display_width(), 9);
+/// println!("{}", prompt.slice(0..4));
+/// ```
+///
+/// With the `serde` feature a line is serialized as the list of its segments.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct StyledLine<'a>(Vec<ColorPrint<'a>>);
+
+impl<'a> StyledLine<'a> {
+    /// Create an empty line
+    pub fn new() -> StyledLine<'a> {
+        StyledLine::default()
+    }
+
+    /// Parse a text with escape codes, like the output of another tool, into styled segments
+    ///
+    /// The colors, underline and text styles of the SGR sequences are kept, background colors
+    /// and every other escape sequence are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::StyledLine;
+    ///
+    /// let line = StyledLine::parse_ansi("\x1b[1;31merror\x1b[0m: not found");
+    /// assert_eq!(line.plain_text(), "error: not found");
+    /// assert_eq!(line.render_debug(), "[fg:red][bold]error[reset]: not found[reset]");
+    /// ```
+    pub fn parse_ansi(text: &'a str) -> StyledLine<'a> {
+        let mut line = StyledLine::new();
+        let mut style = Style::new();
+        let mut start = 0;
+        let mut chars = text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c != '\x1b' {
+                continue;
+            }
+            if start < i {
+                line.push(style.apply(&text[start..i]));
+            }
+
+            match chars.next() {
+                // CSI: parameters up to the final byte, only `m` (SGR) changes the style
+                Some((_, '[')) => {
+                    let params_start = i + 2;
+                    for (end, c) in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&c) {
+                            if c == 'm' {
+                                style.apply_sgr(&text[params_start..end]);
+                            }
+                            break;
+                        }
+                    }
+                }
+                // OSC, DCS, SOS, PM and APC: a string up to BEL or ST (ESC \)
+                Some((_, ']' | 'P' | 'X' | '^' | '_')) => {
+                    while let Some((_, c)) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == '\x1b' && chars.peek().map(|(_, c)| *c) == Some('\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+            start = chars.peek().map_or(text.len(), |(next, _)| *next);
+        }
+        if start < text.len() {
+            line.push(style.apply(&text[start..]));
+        }
+        line
+    }
+
+    /// Add a segment at the end of the line
+    ///
+    /// # Params
+    /// - `segment`: A [`ColorPrint`], or a `&str` or `String` printed without style
+    pub fn with(mut self, segment: impl Into<ColorPrint<'a>>) -> StyledLine<'a> {
+        self.push(segment);
+        self
+    }
+
+    /// Add a segment at the end of the line
+    ///
+    /// # Params
+    /// - `segment`: A [`ColorPrint`], or a `&str` or `String` printed without style
+    pub fn push(&mut self, segment: impl Into<ColorPrint<'a>>) {
+        self.0.push(segment.into());
+    }
+
+    /// Iterate over the segments
+    pub fn segments(&self) -> impl Iterator<Item = &ColorPrint<'a>> {
+        self.0.iter()
+    }
+
+    /// Return true if the line has no text
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|segment| segment.text().is_empty())
+    }
+
+    /// Return the text of every segment without style
+    pub fn plain_text(&self) -> String {
+        self.0.iter().map(|segment| segment.text()).collect()
+    }
+
+    /// Return the number of columns the line takes in the terminal
+    ///
+    /// Wide characters (CJK, emoji) take two columns, see [`display_width`](crate::utils::display_width).
+    pub fn display_width(&self) -> usize {
+        self.0.iter().map(|segment| segment.display_width()).sum()
+    }
+
+    /// Return the characters in `range` of the line, every one keeping the style of its segment
+    ///
+    /// The range counts characters, not bytes, and is clamped to the length of the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrintExt, StyledLine};
+    ///
+    /// let line = "Hello".color(255, 0, 0) + ", world!".color(0, 0, 255);
+    /// assert_eq!(line.slice(3..8).plain_text(), "lo, w");
+    /// ```
+    pub fn slice(&self, range: std::ops::Range<usize>) -> StyledLine<'_> {
+        let mut sliced = StyledLine::new();
+        let mut offset = 0;
+        for segment in &self.0 {
+            let text = segment.text();
+            let len = text.chars().count();
+            let start = range.start.clamp(offset, offset + len) - offset;
+            let end = range.end.clamp(offset, offset + len) - offset;
+            if start < end {
+                let byte = |index: usize| text.char_indices().nth(index).map_or(text.len(), |(byte, _)| byte);
+                sliced.push(segment.restyle(&text[byte(start)..byte(end)]));
+            }
+            offset += len;
+        }
+        sliced
+    }
+
+    /// Apply `style` over the characters in `range`, the rest of the line keeps its style
+    ///
+    /// The fields set in `style` override the ones of the segments, see [`Style::merge`].
+    /// The range counts characters, not bytes, and is clamped to the length of the line.
+    ///
+    /// # Params
+    /// - `range`: The characters to style
+    /// - `style`: The style applied over them
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, Style, StyledLine};
+    ///
+    /// let line = StyledLine::new().with("src/main.rs:3: ").with(ColorPrint::from("let x = ;").bold());
+    /// println!("{}", line.style_range(23..24, &Style::new().underline(None).underline_color(255, 0, 0)));
+    /// ```
+    pub fn style_range(self, range: std::ops::Range<usize>, style: &Style) -> StyledLine<'a> {
+        let mut styled = StyledLine::new();
+        let mut offset = 0;
+        for segment in self.0 {
+            let len = segment.text().chars().count();
+            let start = range.start.clamp(offset, offset + len) - offset;
+            let end = range.end.clamp(offset, offset + len) - offset;
+            offset += len;
+            if start >= end {
+                styled.push(segment);
+                continue;
+            }
+
+            let (before, rest) = segment.split_at_char(start);
+            let (inside, after) = rest.split_at_char(end - start);
+            let merged = inside.style().merge(style);
+            for part in [before, inside.with_style(merged), after] {
+                if !part.text().is_empty() {
+                    styled.push(part);
+                }
+            }
+        }
+        styled
+    }
+
+    /// Apply `style` over every match of `pattern`, the rest of the line keeps its style
+    ///
+    /// The matches are searched in the plain text of the line, so they can span several
+    /// segments. Requires the `regex` feature.
+    ///
+    /// # Params
+    /// - `pattern`: The regex to search
+    /// - `style`: The style applied over the matches, see [`StyledLine::style_range`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, Style};
+    /// use regex::Regex;
+    ///
+    /// let errors = Regex::new(r"E\d{4}").unwrap();
+    /// let line = ColorPrint::from("error[E0308]: mismatched types, see E0308");
+    /// println!("{}", line.highlight_matches(&errors, &Style::new().color(255, 0, 0).bold()));
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn highlight_matches(self, pattern: &regex::Regex, style: &Style) -> StyledLine<'a> {
+        let text = self.plain_text();
+        let matches = pattern.find_iter(&text).map(|found| found.range());
+        self.style_byte_ranges(&text, matches, style)
+    }
+
+    /// Apply `style` over every occurrence of `needle`, the rest of the line keeps its style
+    ///
+    /// Like [`StyledLine::highlight_matches`] for a literal text, the occurrences are searched
+    /// in the plain text of the line and don't overlap.
+    ///
+    /// # Params
+    /// - `needle`: The text to search, nothing is highlighted if it is empty
+    /// - `style`: The style applied over the occurrences, see [`StyledLine::style_range`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polychrome::{ColorPrint, Style};
+    ///
+    /// let filter = "ca";
+    /// for name in ["cargo", "rustc", "cache"] {
+    ///     println!("{}", ColorPrint::from(name).highlight(filter, &Style::new().color(255, 200, 0)));
+    /// }
+    /// ```
+    pub fn highlight(self, needle: &str, style: &Style) -> StyledLine<'a> {
+        if needle.is_empty() {
+            return self;
+        }
+        let text = self.plain_text();
+        let occurrences = text.match_indices(needle).map(|(start, found)| start..start + found.len());
+        self.style_byte_ranges(&text, occurrences, style)
+    }
+
+    /// Apply `style` over the byte `ranges` of `text`, the plain text of the line
+    fn style_byte_ranges(
+        self,
+        text: &str,
+        ranges: impl Iterator<Item = std::ops::Range<usize>>,
+        style: &Style,
+    ) -> StyledLine<'a> {
+        // style_range counts characters, not bytes
+        let mut char_ranges = Vec::new();
+        let (mut chars_before, mut previous_end) = (0, 0);
+        for range in ranges {
+            chars_before += text[previous_end..range.start].chars().count();
+            let len = text[range.clone()].chars().count();
+            char_ranges.push(chars_before..chars_before + len);
+            chars_before += len;
+            previous_end = range.end;
+        }
+        char_ranges.into_iter().fold(self, |line, range| line.style_range(range, style))
+    }
+
+    /// Set the kind of escape codes used to print every segment
+    ///
+    /// # Params
+    /// - `mode`: The color mode, the global one ([`ColorMode::global`](crate::ColorMode::global)) by default
+    pub fn mode(self, mode: crate::ColorMode) -> StyledLine<'a> {
+        StyledLine(self.0.into_iter().map(|segment| segment.mode(mode)).collect())
+    }
+
+    /// Write the styled line to `writer`, without building a `String` first
+    pub fn write_to(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Append the styled line to `buffer`, reusing its allocation
+    pub fn render_into(&self, buffer: &mut String) {
+        use std::fmt::Write;
+        // Writing into a String never fails
+        let _ = write!(buffer, "{}", self);
+    }
+
+    /// Render the line with readable tokens in place of the escape codes, see [`ColorPrint::render_debug`]
+    pub fn render_debug(&self) -> String {
+        self.0.iter().map(|segment| segment.render_debug()).collect()
+    }
+}
+
+impl<'a> From<ColorPrint<'a>> for StyledLine<'a> {
+    fn from(segment: ColorPrint<'a>) -> StyledLine<'a> {
+        StyledLine(vec![segment])
+    }
+}
+
+impl<'a> std::fmt::Display for StyledLine<'a> {
+    /// Print every segment with its own style
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for segment in &self.0 {
+            write!(f, "{}", segment)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> std::ops::Add for ColorPrint<'a> {
+    type Output = StyledLine<'a>;
+
+    fn add(self, other: ColorPrint<'a>) -> StyledLine<'a> {
+        StyledLine(vec![self, other])
+    }
+}
+
+impl<'a> std::ops::Add<ColorPrint<'a>> for StyledLine<'a> {
+    type Output = StyledLine<'a>;
+
+    fn add(mut self, other: ColorPrint<'a>) -> StyledLine<'a> {
+        self.0.push(other);
+        self
+    }
+}
+
+impl<'a> std::ops::Add for StyledLine<'a> {
+    type Output = StyledLine<'a>;
+
+    fn add(mut self, other: StyledLine<'a>) -> StyledLine<'a> {
+        self.0.extend(other.0);
+        self
+    }
+}
+
+impl<'a> std::ops::AddAssign<ColorPrint<'a>> for StyledLine<'a> {
+    fn add_assign(&mut self, other: ColorPrint<'a>) {
+        self.0.push(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorMode, ColorPrintExt};
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_add_segments() {
+        let red = "a".color(255, 0, 0).mode(ColorMode::TrueColor);
+        let line = red + "b".underline(Some("stripe")).mode(ColorMode::TrueColor);
+        assert_eq!(line.to_string(), "\x1b[38;2;255;0;0;4ma\x1b[0m\x1b[9mb\x1b[0m");
+    }
+
+    #[test]
+    fn test_concat_lines() {
+        let mut line = StyledLine::new() + ColorPrint::from("a").mode(ColorMode::None);
+        line += ColorPrint::from(String::from("b")).mode(ColorMode::None);
+        let line = line + StyledLine::from("c".color(0, 0, 255).mode(ColorMode::None));
+        assert_eq!(line.to_string(), "abc");
+    }
+
+    #[test]
+    fn test_spans() {
+        let line = StyledLine::new().with("ab".color(255, 0, 0)).with(String::from("cd")).with("日本".underline(None));
+
+        assert_eq!(line.segments().count(), 3);
+        assert_eq!(line.plain_text(), "abcd日本");
+        assert_eq!(line.display_width(), 8);
+        assert!(!line.is_empty());
+        assert!(StyledLine::new().with("").is_empty());
+    }
+
+    #[test]
+    fn test_slice() {
+        let line = "ab".color(255, 0, 0) + ColorPrint::from("cd") + "日本".underline(None);
+
+        assert_eq!(line.slice(1..5).render_debug(), "[fg:255,0,0][underline]b[reset]cd[reset][underline]日[reset]");
+        assert_eq!(line.slice(2..4).segments().count(), 1);
+        assert_eq!(line.slice(4..100).plain_text(), "日本");
+        assert!(line.slice(3..3).is_empty());
+        assert_eq!(line.slice(7..9).segments().count(), 0);
+    }
+
+    #[test]
+    fn test_write_to_and_render_into() {
+        let line = ("a".color(255, 0, 0) + ColorPrint::from("b")).mode(ColorMode::None);
+
+        let mut bytes = Vec::new();
+        line.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes, b"ab");
+
+        let mut buffer = String::new();
+        line.render_into(&mut buffer);
+        line.render_into(&mut buffer);
+        assert_eq!(buffer, "abab");
+    }
+
+    #[test]
+    fn test_parse_ansi() {
+        let line = StyledLine::parse_ansi("\x1b[38;2;255;0;0;4mred\x1b[24m no underline\x1b[0m plain");
+        assert_eq!(
+            line.render_debug(),
+            "[fg:255,0,0][underline]red[reset][fg:255,0,0] no underline[reset] plain[reset]"
+        );
+
+        let line = StyledLine::parse_ansi("\x1b[38;5;208;3;58;5;1ma\x1b[23;39mb\x1b[m");
+        assert_eq!(
+            line.render_debug(),
+            "[fg:255,135,0][italic][underline_color:205,0,0]a[reset][underline_color:205,0,0]b[reset]"
+        );
+
+        let line = StyledLine::parse_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\ \x1b[2Kdone");
+        assert_eq!(line.plain_text(), "link done");
+        assert_eq!(StyledLine::parse_ansi("").segments().count(), 0);
+        assert_eq!(StyledLine::parse_ansi("\x1b[1m").segments().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_parse_ansi_round_trip() {
+        let (a, b) = ("a", "b");
+        let original = (a.color(1, 2, 3).bold() + b.underline(None).strikethrough()).mode(ColorMode::TrueColor);
+        let rendered = original.to_string();
+        let parsed = StyledLine::parse_ansi(&rendered).mode(ColorMode::TrueColor);
+        assert_eq!(parsed.to_string(), rendered);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_styled_line_serde() {
+        let line = StyledLine::new().with(ColorPrint::from("error").bold()).with(": not found");
+        let json = serde_json::to_string(&line).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"text":"error","style":{"text_styles":["bold"]}},{"text":": not found","style":{}}]"#
+        );
+
+        let parsed: StyledLine = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.render_debug(), line.render_debug());
+        let parsed: StyledLine = serde_json::from_str(r#"[{"text": "plain"}]"#).unwrap();
+        assert_eq!(parsed.plain_text(), "plain");
+    }
+
+    #[test]
+    fn test_style_range() {
+        let red = Style::new().color(255, 0, 0);
+        let line = ColorPrint::from("abcdef").bold().style_range(2..4, &red);
+        assert_eq!(line.render_debug(), "[bold]ab[reset][fg:255,0,0][bold]cd[reset][bold]ef[reset]");
+
+        let line = (ColorPrint::from("ab") + ColorPrint::from(String::from("日本語"))).style_range(1..3, &red);
+        assert_eq!(line.render_debug(), "a[reset][fg:255,0,0]b[reset][fg:255,0,0]日[reset]本語[reset]");
+
+        let line = ColorPrint::from("abc").style_range(2..10, &red).style_range(3..3, &red);
+        assert_eq!(line.render_debug(), "ab[reset][fg:255,0,0]c[reset]");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_highlight_matches() {
+        let numbers = regex::Regex::new(r"\d+").unwrap();
+        let bold = Style::new().bold();
+
+        let line = ColorPrint::from("é 12 and 3").highlight_matches(&numbers, &bold);
+        assert_eq!(line.render_debug(), "é [reset][bold]12[reset] and [reset][bold]3[reset]");
+
+        let line = (ColorPrint::from("a1") + ColorPrint::from("2b").italic()).highlight_matches(&numbers, &bold);
+        assert_eq!(line.render_debug(), "a[reset][bold]1[reset][bold][italic]2[reset][italic]b[reset]");
+
+        let line = ColorPrint::from("none").highlight_matches(&numbers, &bold);
+        assert_eq!(line.render_debug(), "none[reset]");
+    }
+
+    #[test]
+    fn test_highlight() {
+        let bold = Style::new().bold();
+
+        let line = ColorPrint::from("aaa ñaa").highlight("aa", &bold);
+        assert_eq!(line.render_debug(), "[bold]aa[reset]a ñ[reset][bold]aa[reset]");
+
+        let line = (ColorPrint::from("ca") + ColorPrint::from("che").italic()).highlight("ac", &bold);
+        assert_eq!(line.render_debug(), "c[reset][bold]a[reset][bold][italic]c[reset][italic]he[reset]");
+
+        assert_eq!(ColorPrint::from("abc").highlight("", &bold).render_debug(), "abc[reset]");
+        assert_eq!(ColorPrint::from("abc").highlight("x", &bold).render_debug(), "abc[reset]");
+    }
+}
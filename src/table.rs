@@ -0,0 +1,308 @@
+// table.rs
+use crate::utils::display_width;
+use crate::Style;
+use std::fmt::Display;
+
+/// The lines drawn around and between the cells of a [`Table`] or a [`Panel`](crate::Panel)
+///
+/// # Variants
+/// - `Ascii`: `+`, `-` and `|`, printable in every terminal
+/// - `Rounded`: Thin lines with rounded corners, the default
+/// - `Heavy`: Thick lines
+/// - `None`: No lines, the cells are only separated by their padding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BorderStyle {
+    /// `+`, `-` and `|`
+    Ascii,
+    /// `╭─┬─╮`
+    #[default]
+    Rounded,
+    /// `┏━┳━┓`
+    Heavy,
+    /// No lines
+    None,
+}
+
+/// The characters of a border style, `[left, middle, right]` for the top, the separators and the bottom
+pub(crate) struct BorderChars {
+    pub(crate) horizontal: char,
+    pub(crate) vertical: char,
+    pub(crate) top: [char; 3],
+    pub(crate) middle: [char; 3],
+    pub(crate) bottom: [char; 3],
+}
+
+impl BorderStyle {
+    /// Return the characters of the border, `None` for [`BorderStyle::None`]
+    pub(crate) fn chars(&self) -> Option<BorderChars> {
+        let (horizontal, vertical, top, middle, bottom) = match self {
+            BorderStyle::Ascii => ('-', '|', ['+', '+', '+'], ['+', '+', '+'], ['+', '+', '+']),
+            BorderStyle::Rounded => ('─', '│', ['╭', '┬', '╮'], ['├', '┼', '┤'], ['╰', '┴', '╯']),
+            BorderStyle::Heavy => ('━', '┃', ['┏', '┳', '┓'], ['┣', '╋', '┫'], ['┗', '┻', '┛']),
+            BorderStyle::None => return None,
+        };
+        Some(BorderChars { horizontal, vertical, top, middle, bottom })
+    }
+}
+
+/// The position of a text inside a wider cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Alignment {
+    /// Against the left side, the default
+    #[default]
+    Left,
+    /// In the middle, one more space on the right for an odd number of spaces
+    Center,
+    /// Against the right side, e.g. for numbers
+    Right,
+}
+
+/// Pad `text` with spaces to `width` columns, escape codes take no room
+pub(crate) fn align(text: &str, width: usize, alignment: Alignment) -> String {
+    let spaces = width.saturating_sub(display_width(text));
+    let (left, right) = match alignment {
+        Alignment::Left => (0, spaces),
+        Alignment::Center => (spaces / 2, spaces - spaces / 2),
+        Alignment::Right => (spaces, 0),
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+/// Apply `style` to `text`, without any escape code for an empty style
+pub(crate) fn paint(style: &Style, text: &str) -> String {
+    if *style == Style::new() {
+        text.to_string()
+    } else {
+        style.apply(text).to_string()
+    }
+}
+
+/// A table with a header, rows of styled cells and borders
+///
+/// The cells are anything printable, so they keep their own colors, and the columns are as
+/// wide as their widest cell measured without the escape codes. Rows shorter than the others
+/// are filled with empty cells.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::{Alignment, BorderStyle, ColorPrintExt, Style, Table};
+///
+/// let table = Table::new()
+///     .header(["Crate", "Version", "Status"])
+///     .row(["polychrome".to_string(), "2.0.3".to_string(), "ok".color(0, 255, 0).to_string()])
+///     .row(["rand".to_string(), "0.8.5".to_string(), "outdated".color(255, 0, 0).to_string()])
+///     .align(1, Alignment::Right)
+///     .border(BorderStyle::Rounded)
+///     .header_style(Style::new().bold());
+/// println!("{}", table);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    alignments: Vec<Alignment>,
+    border: BorderStyle,
+    border_style: Style,
+    header_style: Style,
+    padding: usize,
+}
+
+impl Table {
+    /// Create an empty table with rounded borders and a padding of 1
+    pub fn new() -> Table {
+        Table { padding: 1, ..Table::default() }
+    }
+
+    /// Set the header, printed above the rows and separated from them by a line
+    ///
+    /// # Params
+    /// - `cells`: The title of every column
+    pub fn header<I>(mut self, cells: I) -> Table
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        self.header = cells.into_iter().map(|cell| cell.to_string()).collect();
+        self
+    }
+
+    /// Add a row below the previous ones
+    ///
+    /// # Params
+    /// - `cells`: The cells of the row, from the first column
+    pub fn row<I>(mut self, cells: I) -> Table
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        self.push_row(cells);
+        self
+    }
+
+    /// Add a row below the previous ones, e.g. from a loop
+    pub fn push_row<I>(&mut self, cells: I)
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        self.rows.push(cells.into_iter().map(|cell| cell.to_string()).collect());
+    }
+
+    /// Set the alignment of the cells of a column, the header included
+    ///
+    /// # Params
+    /// - `column`: The index of the column, from 0
+    /// - `alignment`: The alignment of its cells, left by default
+    pub fn align(mut self, column: usize, alignment: Alignment) -> Table {
+        if self.alignments.len() <= column {
+            self.alignments.resize(column + 1, Alignment::default());
+        }
+        self.alignments[column] = alignment;
+        self
+    }
+
+    /// Set the lines drawn around and between the cells
+    pub fn border(mut self, border: BorderStyle) -> Table {
+        self.border = border;
+        self
+    }
+
+    /// Set the style of the border lines, e.g. a dim color
+    pub fn border_style(mut self, style: Style) -> Table {
+        self.border_style = style;
+        self
+    }
+
+    /// Set the style of the header cells, added to their own style
+    pub fn header_style(mut self, style: Style) -> Table {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the number of spaces on both sides of every cell
+    pub fn padding(mut self, padding: usize) -> Table {
+        self.padding = padding;
+        self
+    }
+
+    /// Return the number of columns, the length of the longest row or of the header
+    pub fn columns(&self) -> usize {
+        self.rows.iter().map(Vec::len).chain([self.header.len()]).max().unwrap_or(0)
+    }
+
+    /// Return the width of every column without its padding
+    fn widths(&self) -> Vec<usize> {
+        let mut widths = vec![0; self.columns()];
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(display_width(cell));
+            }
+        }
+        widths
+    }
+
+    /// Write a line of the border, `[left, middle, right]` joining the horizontal lines of the columns
+    fn write_rule(&self, f: &mut std::fmt::Formatter<'_>, widths: &[usize], joints: [char; 3]) -> std::fmt::Result {
+        let Some(chars) = self.border.chars() else {
+            return Ok(());
+        };
+        let mut rule = String::from(joints[0]);
+        for (i, width) in widths.iter().enumerate() {
+            if i > 0 {
+                rule.push(joints[1]);
+            }
+            rule.extend(std::iter::repeat_n(chars.horizontal, width + 2 * self.padding));
+        }
+        rule.push(joints[2]);
+        writeln!(f, "{}", paint(&self.border_style, &rule))
+    }
+
+    fn write_row(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        widths: &[usize],
+        row: &[String],
+        header: bool,
+    ) -> std::fmt::Result {
+        let vertical = self.border.chars().map(|chars| paint(&self.border_style, &chars.vertical.to_string()));
+        let padding = " ".repeat(self.padding);
+        if let Some(vertical) = &vertical {
+            f.write_str(vertical)?;
+        }
+        for (i, width) in widths.iter().enumerate() {
+            if i > 0 {
+                f.write_str(vertical.as_deref().unwrap_or(""))?;
+            }
+            let cell = row.get(i).map_or("", String::as_str);
+            let cell = if header { paint(&self.header_style, cell) } else { cell.to_string() };
+            let alignment = self.alignments.get(i).copied().unwrap_or_default();
+            write!(f, "{}{}{}", padding, align(&cell, *width, alignment), padding)?;
+        }
+        if let Some(vertical) = &vertical {
+            f.write_str(vertical)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+        let chars = self.border.chars();
+        if let Some(chars) = &chars {
+            self.write_rule(f, &widths, chars.top)?;
+        }
+        if !self.header.is_empty() {
+            self.write_row(f, &widths, &self.header, true)?;
+            if let Some(chars) = &chars {
+                self.write_rule(f, &widths, chars.middle)?;
+            }
+        }
+        for row in &self.rows {
+            self.write_row(f, &widths, row, false)?;
+        }
+        if let Some(chars) = &chars {
+            self.write_rule(f, &widths, chars.bottom)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let table = Table::new()
+            .header(["name", "n"])
+            .row(["a", "10"])
+            .row(["long name", "2"])
+            .align(1, Alignment::Right);
+        let expected = "\
+╭───────────┬────╮
+│ name      │  n │
+├───────────┼────┤
+│ a         │ 10 │
+│ long name │  2 │
+╰───────────┴────╯
+";
+        assert_eq!(table.to_string(), expected);
+
+        let table = Table::new().row(["x"]).row(["y", "z"]).border(BorderStyle::Ascii).padding(0);
+        assert_eq!(table.to_string(), "+-+-+\n|x| |\n|y|z|\n+-+-+\n");
+        let table = Table::new().header(["a", "b"]).row(["ccc", "d"]).border(BorderStyle::None);
+        assert_eq!(table.to_string(), " a    b \n ccc  d \n");
+        assert_eq!(Table::new().border(BorderStyle::None).to_string(), "");
+    }
+
+    #[test]
+    fn test_table_styled_cells() {
+        let styled = Style::new().color(255, 0, 0).mode(crate::ColorMode::TrueColor).apply("ok").to_string();
+        let table = Table::new().row([styled.as_str(), "x"]).row(["fail", "y"]).border(BorderStyle::Heavy);
+        let lines: Vec<String> = table.to_string().lines().map(crate::utils::strip_ansi).collect();
+        assert_eq!(lines, ["┏━━━━━━┳━━━┓", "┃ ok   ┃ x ┃", "┃ fail ┃ y ┃", "┗━━━━━━┻━━━┛"]);
+        assert_eq!(align("ab", 5, Alignment::Center), " ab  ");
+    }
+}
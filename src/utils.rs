@@ -0,0 +1,611 @@
+// utils.rs
+use crate::{ColorMode, ColorPrint, Style};
+use std::borrow::Cow;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use unicode_width::UnicodeWidthStr;
+
+/// Return the escape code that resets every style and color
+///
+/// Useful when composing output by hand and an explicit reset is needed
+/// in the middle of it.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::ColorPrintExt;
+/// use polychrome::utils::reset_code;
+///
+/// println!("{}{}plain again", "Hello, world!".color(255, 0, 0), reset_code());
+/// ```
+#[cfg(feature = "ansi")]
+pub fn reset_code() -> &'static str {
+    "\x1b[0m"
+}
+
+/// Detect the best color mode supported by the terminal
+///
+/// The environment is only read the first time, later calls return the same mode. Every text
+/// without its own mode is printed with it, unless another one is set with
+/// [`ColorMode::set_global`] or [`ColorChoice::Always`](crate::ColorChoice::Always) forces colors.
+///
+/// - Standard output is not a terminal (redirected to a file or a pipe): [`ColorMode::None`]
+/// - `NO_COLOR` set and not empty: [`ColorMode::None`]
+/// - `COLORTERM=truecolor` or `COLORTERM=24bit`: [`ColorMode::TrueColor`]
+/// - Terminals known to support 24-bit colors (Windows Terminal, iTerm2, WezTerm, kitty,
+///   VS Code, ...) from `WT_SESSION`, `TERM_PROGRAM` or `TERM`: [`ColorMode::TrueColor`]
+/// - `TERM` ending with `256color` or the macOS Terminal: [`ColorMode::Ansi256`]
+/// - `TERM=dumb` or no `TERM`: [`ColorMode::None`]
+/// - Any other `TERM`: [`ColorMode::Ansi16`]
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::detect_color_mode;
+///
+/// println!("Colors supported: {:?}", detect_color_mode());
+/// ```
+pub fn detect_color_mode() -> ColorMode {
+    static DETECTED: OnceLock<ColorMode> = OnceLock::new();
+    *DETECTED.get_or_init(|| {
+        if !std::io::stdout().is_terminal() {
+            return ColorMode::None;
+        }
+        color_mode_from_env(|name| std::env::var(name).ok())
+    })
+}
+
+/// Return true if the terminal supports 24-bit colors
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::supports_truecolor;
+///
+/// if !supports_truecolor() {
+///     println!("Colors will be approximated");
+/// }
+/// ```
+pub fn supports_truecolor() -> bool {
+    detect_color_mode() == ColorMode::TrueColor
+}
+
+/// Return true if the terminal draws the underline shapes (`double`, `curly`, `dotted`, `dashed`)
+///
+/// Terminals without them ignore the escape code or misread it, so the shapes are printed as a
+/// normal underline there. Unless it was set with [`set_underline_styles`], the support is
+/// detected the first time from the environment: kitty, WezTerm, ghostty, foot, contour,
+/// iTerm2, VS Code, mintty and VTE terminals (GNOME Terminal, Tilix, ...) since 0.52.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::ColorPrintExt;
+/// use polychrome::utils::supports_underline_styles;
+///
+/// let text = "misspeled";
+/// // A normal underline where curly ones aren't supported
+/// println!("{}", text.underline(Some("curly")));
+/// println!("Curly underlines: {}", supports_underline_styles());
+/// ```
+pub fn supports_underline_styles() -> bool {
+    static DETECTED: OnceLock<bool> = OnceLock::new();
+    match UNDERLINE_STYLES.load(Ordering::Relaxed) {
+        1 => false,
+        2 => true,
+        _ => *DETECTED.get_or_init(|| underline_styles_from_env(|name| std::env::var(name).ok())),
+    }
+}
+
+/// Set if the underline shapes are printed, instead of detecting it from the environment
+///
+/// # Params
+/// - `supported`: `false` prints a normal underline instead of the shapes
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::{set_underline_styles, supports_underline_styles};
+///
+/// set_underline_styles(true);
+/// assert!(supports_underline_styles());
+/// ```
+pub fn set_underline_styles(supported: bool) {
+    UNDERLINE_STYLES.store(if supported { 2 } else { 1 }, Ordering::Relaxed);
+}
+
+/// Set if a color code is skipped when a character has the same color as the previous one
+///
+/// Texts painted character by character, with a gradient or [`ColorPrint::polychrome`], only
+/// write a color code when the color changes. Turning it off writes one before every character,
+/// e.g. for a tool splitting the output into characters with their own escape codes.
+///
+/// # Params
+/// - `compact`: `false` writes a color code before every character, `true` by default
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::{compact_colors, set_compact_colors};
+/// use polychrome::{Color, ColorMode, ColorPrint, Repeat};
+///
+/// ColorMode::set_global(ColorMode::TrueColor);
+/// assert_eq!(ColorPrint::gradient("abc", Color::RED, Color::RED).matches("\x1b[38;").count(), 1);
+///
+/// set_compact_colors(false);
+/// assert!(!compact_colors());
+/// assert_eq!(ColorPrint::gradient("abc", Color::RED, Color::RED).matches("\x1b[38;").count(), 3);
+/// let painted = ColorPrint::polychrome_with("abc", &[Color::RED], 1, Repeat::Cycle);
+/// assert_eq!(painted.matches("\x1b[38;").count(), 3);
+/// ```
+#[cfg(feature = "ansi")]
+pub fn set_compact_colors(compact: bool) {
+    COMPACT_COLORS.store(compact, Ordering::Relaxed);
+}
+
+/// Return true if repeated color codes are skipped, see [`set_compact_colors`]
+#[cfg(feature = "ansi")]
+pub fn compact_colors() -> bool {
+    COMPACT_COLORS.load(Ordering::Relaxed)
+}
+
+/// Whether the per-character painters skip the repeated color codes
+#[cfg(feature = "ansi")]
+static COMPACT_COLORS: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(true);
+
+/// Return true if the terminal has a dark background
+///
+/// Unless it was set with [`set_dark_background`], it is detected the first time from the
+/// `COLORFGBG` variable set by rxvt, Konsole and some other terminals, e.g. `15;0` for white
+/// on black. Without it the background is taken as dark, the default of most terminals.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::is_dark_background;
+///
+/// let accent = if is_dark_background() { (255, 200, 0) } else { (120, 80, 0) };
+/// println!("{:?}", accent);
+/// ```
+pub fn is_dark_background() -> bool {
+    static DETECTED: OnceLock<bool> = OnceLock::new();
+    match DARK_BACKGROUND.load(Ordering::Relaxed) {
+        1 => false,
+        2 => true,
+        _ => *DETECTED.get_or_init(|| dark_background_from_env(|name| std::env::var(name).ok())),
+    }
+}
+
+/// Set if the terminal background is dark, instead of detecting it from the environment
+///
+/// # Params
+/// - `dark`: `true` for a dark background, `false` for a light one
+pub fn set_dark_background(dark: bool) {
+    DARK_BACKGROUND.store(if dark { 2 } else { 1 }, Ordering::Relaxed);
+}
+
+/// 0 while the background is detected, 1 and 2 once set as light or dark
+static DARK_BACKGROUND: AtomicU8 = AtomicU8::new(0);
+
+/// Find if the background is dark from the environment variables returned by `var`
+fn dark_background_from_env(var: impl Fn(&str) -> Option<String>) -> bool {
+    // `fg;bg` or `fg;default;bg`, the background is the last color index
+    let background = var("COLORFGBG").and_then(|value| value.rsplit(';').next()?.parse::<u8>().ok());
+    // Every index is dark except white (7) and the bright colors but dark gray (8)
+    !matches!(background, Some(7 | 9..=15))
+}
+
+/// Return true when running in xterm, which prints `\x1b[21m` as a double underline
+pub(crate) fn is_xterm() -> bool {
+    static DETECTED: OnceLock<bool> = OnceLock::new();
+    *DETECTED.get_or_init(|| std::env::var_os("XTERM_VERSION").is_some())
+}
+
+/// 0 while the underline shapes support is detected, 1 and 2 once set as unsupported or supported
+static UNDERLINE_STYLES: AtomicU8 = AtomicU8::new(0);
+
+/// `TERM_PROGRAM` values of terminals drawing the underline shapes
+const UNDERLINE_STYLES_PROGRAMS: [&str; 5] = ["iTerm.app", "WezTerm", "vscode", "ghostty", "mintty"];
+
+/// `TERM` prefixes of terminals drawing the underline shapes
+const UNDERLINE_STYLES_TERMS: [&str; 5] = ["xterm-kitty", "xterm-ghostty", "wezterm", "foot", "contour"];
+
+/// Find if the underline shapes are supported from the environment variables returned by `var`
+fn underline_styles_from_env(var: impl Fn(&str) -> Option<String>) -> bool {
+    if var("KITTY_WINDOW_ID").is_some() {
+        return true;
+    }
+    if var("TERM_PROGRAM").is_some_and(|program| UNDERLINE_STYLES_PROGRAMS.contains(&program.as_str())) {
+        return true;
+    }
+    // VTE_VERSION is 5200 for VTE 0.52.0, the first one with underline shapes
+    if var("VTE_VERSION").and_then(|version| version.parse::<u32>().ok()).is_some_and(|version| version >= 5200) {
+        return true;
+    }
+    var("TERM").is_some_and(|term| UNDERLINE_STYLES_TERMS.iter().any(|known| term.starts_with(known)))
+}
+
+/// `TERM_PROGRAM` values of terminals supporting 24-bit colors
+const TRUECOLOR_PROGRAMS: [&str; 5] = ["iTerm.app", "WezTerm", "vscode", "Hyper", "ghostty"];
+
+/// `TERM` values (or prefixes) of terminals supporting 24-bit colors
+const TRUECOLOR_TERMS: [&str; 7] = [
+    "xterm-direct",
+    "xterm-kitty",
+    "xterm-ghostty",
+    "alacritty",
+    "wezterm",
+    "foot",
+    "contour",
+];
+
+/// Find the color mode from the environment variables returned by `var`
+fn color_mode_from_env(var: impl Fn(&str) -> Option<String>) -> ColorMode {
+    // https://no-color.org
+    if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return ColorMode::None;
+    }
+
+    if let Some(colorterm) = var("COLORTERM") {
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorMode::TrueColor;
+        }
+    }
+
+    // Windows Terminal and ConEmu don't always set TERM
+    if var("WT_SESSION").is_some() || var("ConEmuANSI").is_some_and(|value| value == "ON") {
+        return ColorMode::TrueColor;
+    }
+
+    match var("TERM_PROGRAM").as_deref() {
+        Some(program) if TRUECOLOR_PROGRAMS.contains(&program) => return ColorMode::TrueColor,
+        Some("Apple_Terminal") => return ColorMode::Ansi256,
+        _ => {}
+    }
+
+    let is_truecolor_term = |term: &str| {
+        TRUECOLOR_TERMS.iter().any(|known| term.starts_with(known)) || term.ends_with("truecolor")
+    };
+
+    match var("TERM") {
+        Some(term) if is_truecolor_term(&term) => ColorMode::TrueColor,
+        Some(term) if term == "dumb" || term.is_empty() => ColorMode::None,
+        Some(term) if term.ends_with("256color") => ColorMode::Ansi256,
+        Some(_) => ColorMode::Ansi16,
+        None => ColorMode::None,
+    }
+}
+
+/// Return the number of columns the text takes in the terminal, ignoring escape codes
+///
+/// Wide characters (CJK, emoji) take two columns and combining marks none.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::ColorPrintExt;
+/// use polychrome::utils::display_width;
+///
+/// assert_eq!(display_width(&"Hello".color(255, 0, 0).to_string()), 5);
+/// assert_eq!(display_width("日本語"), 6);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    display_width_with_tabs(text, 1)
+}
+
+/// Return the number of visible columns of the text, the same as [`display_width`]
+///
+/// # Example
+///
+/// ```
+/// use polychrome::ColorPrintExt;
+/// use polychrome::utils::visible_width;
+///
+/// let label = "✔ 完了".color(0, 255, 0).to_string();
+/// println!("{}{}|", label, " ".repeat(10 - visible_width(&label)));
+/// ```
+pub fn visible_width(text: &str) -> usize {
+    display_width(text)
+}
+
+/// Return the number of columns the text takes in the terminal with tab stops every `tab_width` columns
+///
+/// A tab counts as the columns up to the next tab stop instead of a single column.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::display_width_with_tabs;
+///
+/// assert_eq!(display_width_with_tabs("a\tb", 4), 5);
+/// ```
+pub fn display_width_with_tabs(text: &str, tab_width: usize) -> usize {
+    let tab_width = tab_width.max(1);
+    let mut width = 0;
+    for (i, segment) in strip_ansi(text).split('\t').enumerate() {
+        if i > 0 {
+            width += tab_width - width % tab_width;
+        }
+        width += segment.width();
+    }
+    width
+}
+
+/// Split the text into the characters the user sees
+///
+/// With the `unicode` feature these are the extended grapheme clusters, so `"👨‍👩‍👧"` or an `e`
+/// followed by a combining accent are a single item. Without it every `char` is an item.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::graphemes;
+///
+/// assert_eq!(graphemes("abc").count(), 3);
+/// # #[cfg(feature = "unicode")]
+/// assert_eq!(graphemes("e\u{301}👨\u{200d}👩\u{200d}👧").collect::<Vec<_>>(), ["e\u{301}", "👨\u{200d}👩\u{200d}👧"]);
+/// ```
+pub fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    #[cfg(feature = "unicode")]
+    let graphemes = unicode_segmentation::UnicodeSegmentation::graphemes(text, true);
+    #[cfg(not(feature = "unicode"))]
+    let graphemes = text.char_indices().map(move |(i, c)| &text[i..i + c.len_utf8()]);
+    graphemes
+}
+
+/// Remove every escape sequence from the text, keeping only what is printed
+///
+/// Removes CSI sequences (colors and styles), OSC sequences (titles and hyperlinks, ended by
+/// `BEL` or `ESC \`), the other string sequences (`DCS`, `SOS`, `PM` and `APC`) and
+/// two-character escapes.
+///
+/// # Example
+///
+/// ```
+/// use polychrome::ColorPrintExt;
+/// use polychrome::utils::strip_ansi;
+///
+/// let styled = "Hello, world!".color(255, 0, 0).to_string();
+/// assert_eq!(strip_ansi(&styled), "Hello, world!");
+/// assert_eq!(strip_ansi("\x1b]8;;https://example.com\x07link\x1b]8;;\x07"), "link");
+/// ```
+pub fn strip_ansi(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('\x1b') {
+        plain.push_str(&rest[..start]);
+        rest = &rest[start + escape_len(&rest[start..])..];
+    }
+    plain.push_str(rest);
+    plain
+}
+
+/// Return the length in bytes of the escape sequence at the start of `text`, which starts with `\x1b`
+pub(crate) fn escape_len(text: &str) -> usize {
+    let mut chars = text.char_indices().skip(1).peekable();
+    match chars.next() {
+        // CSI: parameters up to the final byte
+        Some((_, '[')) => {
+            for (i, c) in chars {
+                if ('\x40'..='\x7e').contains(&c) {
+                    return i + 1;
+                }
+            }
+        }
+        // OSC, DCS, SOS, PM and APC: a string up to BEL or ST (ESC \)
+        Some((_, ']' | 'P' | 'X' | '^' | '_')) => {
+            while let Some((i, c)) = chars.next() {
+                if c == '\x07' {
+                    return i + 1;
+                }
+                if c == '\x1b' && chars.peek().is_some_and(|(_, next)| *next == '\\') {
+                    return i + 2;
+                }
+            }
+        }
+        // Any other escape is two characters long
+        Some((i, c)) => return i + c.len_utf8(),
+        None => {}
+    }
+    text.len()
+}
+
+/// Layout the items into as many columns as fit in `width`, like `ls` does
+///
+/// Every cell is padded to the widest item (measured with [`display_width`], so styled
+/// items keep their codes) and columns are separated by `gap` spaces.
+///
+/// # Params
+/// - `items`: The items to layout, filled row by row
+/// - `width`: The available width in columns
+/// - `gap`: The number of spaces between columns
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::columns;
+///
+/// let items: Vec<String> = ["src", "Cargo.toml", "README.md"].iter().map(|s| s.to_string()).collect();
+/// println!("{}", columns(&items, 80, 2));
+/// ```
+pub fn columns(items: &[String], width: usize, gap: usize) -> String {
+    let cell_width = items.iter().map(|item| display_width(item)).max().unwrap_or(0);
+    let count = ((width + gap) / (cell_width + gap).max(1)).max(1);
+
+    items
+        .chunks(count)
+        .map(|row| {
+            let mut line = String::new();
+            for (i, item) in row.iter().enumerate() {
+                line.push_str(item);
+                if i + 1 < row.len() {
+                    let padding = cell_width - display_width(item) + gap;
+                    line.push_str(&" ".repeat(padding));
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Style the lines alternately with `even` and `odd`, starting with `even`
+///
+/// Alternating a background color makes long listings and table bodies easier to follow.
+///
+/// # Params
+/// - `lines`: The lines, `&str`, `String` or `Cow<str>`
+/// - `even`: The style of the first line and every other line after it
+/// - `odd`: The style of the second line and every other line after it
+///
+/// # Example
+///
+/// ```
+/// use polychrome::utils::zebra;
+/// use polychrome::Style;
+///
+/// let files = ["Cargo.toml", "README.md", "src/lib.rs", "src/color.rs"];
+/// for line in zebra(files, &Style::new(), &Style::new().bg((40, 40, 40))) {
+///     println!("{}", line);
+/// }
+/// ```
+pub fn zebra<'a, I>(lines: I, even: &Style, odd: &Style) -> impl Iterator<Item = ColorPrint<'a>>
+where
+    I: IntoIterator,
+    I::Item: Into<Cow<'a, str>>,
+{
+    let styles = [even.clone(), odd.clone()];
+    lines.into_iter().enumerate().map(move |(i, line)| styles[i % 2].apply(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "ansi")]
+    use crate::ColorPrintExt;
+
+    #[test]
+    fn test_columns() {
+        let items: Vec<String> = ["a", "bbb", "cc", "dddd", "e"].iter().map(|s| s.to_string()).collect();
+
+        // Cells are 4 wide plus a gap of 2: three columns fit in 16
+        assert_eq!(columns(&items, 16, 2), "a     bbb   cc\ndddd  e");
+        assert_eq!(columns(&items, 3, 2), "a\nbbb\ncc\ndddd\ne");
+    }
+
+    #[test]
+    fn test_color_mode_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+        };
+
+        assert_eq!(color_mode_from_env(env(&[("COLORTERM", "truecolor"), ("TERM", "xterm")])), ColorMode::TrueColor);
+        assert_eq!(color_mode_from_env(env(&[("COLORTERM", "24bit")])), ColorMode::TrueColor);
+        assert_eq!(color_mode_from_env(env(&[("TERM", "xterm-256color")])), ColorMode::Ansi256);
+        assert_eq!(color_mode_from_env(env(&[("TERM", "linux")])), ColorMode::Ansi16);
+        assert_eq!(color_mode_from_env(env(&[("TERM", "dumb")])), ColorMode::None);
+        assert_eq!(color_mode_from_env(env(&[("WT_SESSION", "1234")])), ColorMode::TrueColor);
+        assert_eq!(color_mode_from_env(env(&[("TERM", "xterm-kitty")])), ColorMode::TrueColor);
+        assert_eq!(color_mode_from_env(env(&[("TERM", "screen-truecolor")])), ColorMode::TrueColor);
+        assert_eq!(
+            color_mode_from_env(env(&[("TERM_PROGRAM", "iTerm.app"), ("TERM", "xterm-256color")])),
+            ColorMode::TrueColor
+        );
+        assert_eq!(
+            color_mode_from_env(env(&[("TERM_PROGRAM", "Apple_Terminal"), ("TERM", "xterm")])),
+            ColorMode::Ansi256
+        );
+        assert_eq!(color_mode_from_env(env(&[])), ColorMode::None);
+        assert_eq!(color_mode_from_env(env(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")])), ColorMode::None);
+        assert_eq!(color_mode_from_env(env(&[("NO_COLOR", ""), ("TERM", "xterm")])), ColorMode::Ansi16);
+    }
+
+    #[test]
+    fn test_dark_background_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+        };
+
+        assert!(dark_background_from_env(env(&[("COLORFGBG", "15;0")])));
+        assert!(dark_background_from_env(env(&[("COLORFGBG", "7;default;8")])));
+        assert!(!dark_background_from_env(env(&[("COLORFGBG", "0;15")])));
+        assert!(!dark_background_from_env(env(&[("COLORFGBG", "0;default;7")])));
+        assert!(dark_background_from_env(env(&[("COLORFGBG", "bad")])));
+        assert!(dark_background_from_env(env(&[])));
+    }
+
+    #[test]
+    fn test_underline_styles_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+        };
+
+        assert!(underline_styles_from_env(env(&[("TERM", "xterm-kitty")])));
+        assert!(underline_styles_from_env(env(&[("TERM_PROGRAM", "WezTerm"), ("TERM", "xterm-256color")])));
+        assert!(underline_styles_from_env(env(&[("VTE_VERSION", "7600"), ("TERM", "xterm-256color")])));
+        assert!(!underline_styles_from_env(env(&[("VTE_VERSION", "5002"), ("TERM", "xterm-256color")])));
+        assert!(!underline_styles_from_env(env(&[("TERM", "xterm-256color")])));
+        assert!(!underline_styles_from_env(env(&[("TERM", "linux")])));
+        assert!(!underline_styles_from_env(env(&[])));
+    }
+
+    #[test]
+    fn test_display_width_with_tabs() {
+        assert_eq!(display_width_with_tabs("a\tb", 4), 5);
+        assert_eq!(display_width_with_tabs("abcd\tb", 4), 9);
+        assert_eq!(display_width_with_tabs("\x1b[1ma\x1b[0m\tb", 8), 9);
+        assert_eq!(display_width("a\tb"), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_reset_code() {
+        assert_eq!(reset_code(), "\x1b[0m");
+
+        let line = crate::StyledLine::new()
+            .with("Hello".color(255, 0, 0).mode(ColorMode::TrueColor))
+            .with(reset_code())
+            .with("tail");
+        assert_eq!(line.to_string(), "\x1b[38;2;255;0;0;4mHello\x1b[0m\x1b[0mtail");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn test_columns_with_styled_items() {
+        let items = vec!["ab".color(255, 0, 0).to_string(), "c".to_string(), "d".to_string()];
+
+        assert_eq!(display_width(&items[0]), 2);
+        assert_eq!(columns(&items, 8, 1), format!("{} c  d", items[0]));
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("plain"), "plain");
+        assert_eq!(strip_ansi("\x1b[38;2;255;0;0m\x1b[4mred\x1b[0m text"), "red text");
+        assert_eq!(strip_ansi("\x1b]0;title\x1b\\after"), "after");
+        assert_eq!(strip_ansi("\x1b7saved\x1b8"), "saved");
+        assert_eq!(strip_ansi("cut\x1b["), "cut");
+        assert_eq!(display_width("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), 4);
+    }
+
+    #[test]
+    fn test_wide_characters() {
+        assert_eq!(display_width("日本"), 4);
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("🦀"), 2);
+        assert_eq!(visible_width("\x1b[31m한글\x1b[0m!"), 5);
+        assert_eq!(display_width_with_tabs("日\tb", 4), 5);
+    }
+
+    #[test]
+    fn test_zebra() {
+        let (even, odd) = (Style::new().bold(), Style::new().bg((40, 40, 40)));
+        let rows = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let lines: Vec<ColorPrint> = zebra(rows, &even, &odd).collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!([lines[0].style(), lines[1].style(), lines[2].style()], [&even, &odd, &even]);
+        assert_eq!(lines[1].text(), "b");
+        assert_eq!(zebra(Vec::<&str>::new(), &even, &odd).count(), 0);
+    }
+}